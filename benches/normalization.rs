@@ -0,0 +1,66 @@
+//! Compares when inlined values are normalized after slicing, run once per mode:
+//! - `cargo bench`: slicing moves the inlined bytes (the default)
+//! - `cargo bench --features inline-offset`: slicing only moves the offset, normalizing lazily
+//! - `cargo bench --features eager-normalization`: slicing moves the offset, then normalizes
+//!
+//! Built without a benchmark framework to keep the crate free of dependencies, so the numbers
+//! are rough averages over many iterations.
+
+use std::{
+    hash::{BuildHasher, RandomState},
+    hint::black_box,
+    time::Instant,
+};
+
+use short_str::{ScalarHash, ShortStr};
+
+const ITERATIONS: u32 = 1_000_000;
+const WORDS: [&str; 4] = ["hello, world", "short", "identifier", "key=value"];
+
+fn bench(name: &str, mut operation: impl FnMut(ShortStr<'static>, usize) -> usize) {
+    let words = WORDS.map(ShortStr::from);
+    let mut total = 0;
+    let start = Instant::now();
+    for iteration in 0..ITERATIONS {
+        let word = words[iteration as usize % words.len()];
+        total += operation(black_box(word), black_box(iteration as usize % 3));
+    }
+    let elapsed = start.elapsed();
+    black_box(total);
+    println!("{name:<24} {:>8.2} ns/iter", elapsed.as_nanos() as f64 / ITERATIONS as f64);
+}
+
+fn main() {
+    let mode = if cfg!(feature = "eager-normalization") {
+        "eager normalization"
+    } else if cfg!(feature = "inline-offset") {
+        "lazy normalization"
+    } else {
+        "moving bytes (default)"
+    };
+    println!("mode: {mode}");
+
+    let state = RandomState::new();
+    let needle = ShortStr::from("ell");
+
+    bench("slice", |word, start| word.slice(start..word.len() - 1).len());
+    bench("slice twice", |word, start| {
+        word.slice(start..).slice(..word.len() - start - 1).len()
+    });
+    bench("slice + eq", |word, start| {
+        (word.slice(start..start + 3) == needle) as usize
+    });
+    bench("slice + cmp", |word, start| {
+        word.slice(start..start + 3).cmp(&needle) as usize
+    });
+    bench("slice + hash", |word, start| {
+        state.hash_one(word.slice(start..start + 3)) as usize
+    });
+    bench("slice + scalar hash", |word, start| {
+        state.hash_one(ScalarHash(word.slice(start..start + 3))) as usize
+    });
+    bench("slice + normalize + 4 eq", |word, start| {
+        let slice = word.slice(start..start + 3).normalize();
+        (0..4).filter(|_| black_box(slice) == needle).count()
+    });
+}
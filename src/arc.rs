@@ -0,0 +1,197 @@
+use alloc::sync::Arc;
+use core::ptr::slice_from_raw_parts;
+
+use crate::ShortStr;
+
+/// A reference counted [`ShortStr`], which inlines its content the same way but shares an
+/// [`Arc<str>`] when the content doesn't fit. The [`Arc`] is stored as a thin pointer (to the
+/// data) next to the length, keeping [`ArcShortStr`] the same size as [`&str`].
+///
+/// Cloning only copies inlined values, and only bumps the reference count of shared ones.
+///
+/// # Examples
+/// ```
+/// use short_str::ArcShortStr;
+///
+/// let inlined = ArcShortStr::from("hello");
+/// let shared = ArcShortStr::from("stronger, faster, better, morer?");
+/// let clone = shared.clone();
+/// assert_eq!(inlined.is_shared(), false);
+/// assert_eq!(shared.is_shared(), true);
+/// assert_eq!(shared.as_ptr(), clone.as_ptr());
+/// ```
+pub struct ArcShortStr {
+    // either inlined, or a facade of an Arc<str> that this ArcShortStr holds one count of
+    repr: ShortStr<'static>,
+}
+
+impl ArcShortStr {
+    /// An empty [`ArcShortStr`], which never allocates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use short_str::ArcShortStr;
+    ///
+    /// assert_eq!(ArcShortStr::EMPTY, "");
+    /// ```
+    pub const EMPTY: ArcShortStr = ArcShortStr { repr: ShortStr::EMPTY };
+
+    #[inline(always)]
+    /// Produce an [`ArcShortStr`] from an [`Arc<str>`], taking over its count if the content
+    /// can't be inlined.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use short_str::ArcShortStr;
+    ///
+    /// let arc: Arc<str> = Arc::from("this string is way too long to be inlined");
+    /// let string = ArcShortStr::from_arc(arc.clone());
+    /// assert_eq!(string.as_ptr(), arc.as_ptr());
+    /// ```
+    pub fn from_arc(value: Arc<str>) -> Self {
+        Self::inline_or_share(value)
+    }
+
+    #[inline(always)]
+    /// Borrow the [`ArcShortStr`] as a [`ShortStr`]. Inlined content is copied over, while
+    /// shared content produces a facade of the shared [`str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{ArcShortStr, ShortStr};
+    ///
+    /// let string = ArcShortStr::from("hello");
+    /// let short: ShortStr = string.as_short_str();
+    /// assert_eq!(short, "hello");
+    /// ```
+    pub const fn as_short_str(&self) -> ShortStr<'_> {
+        self.repr
+    }
+
+    #[inline(always)]
+    /// View the [`ArcShortStr`] as a [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ArcShortStr;
+    ///
+    /// let string = ArcShortStr::from("hello");
+    /// assert_eq!(string.as_str(), "hello");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        self.repr.as_str()
+    }
+
+    #[inline(always)]
+    /// Returns if the content of the [`ArcShortStr`] is a shared [`Arc<str>`] (not inlined) or
+    /// not.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ArcShortStr;
+    ///
+    /// assert_eq!(ArcShortStr::from("i am too big to fit inside the structure").is_shared(), true);
+    /// assert_eq!(ArcShortStr::from("small").is_shared(), false);
+    /// ```
+    pub const fn is_shared(&self) -> bool {
+        self.repr.is_str()
+    }
+
+    #[inline(always)]
+    /// Returns the number of bytes in the [`ArcShortStr`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ArcShortStr;
+    ///
+    /// assert_eq!(ArcShortStr::from("hello").len(), 5);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.repr.len()
+    }
+
+    #[inline(always)]
+    /// Returns `true` if the [`ArcShortStr`] has a length of zero bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ArcShortStr;
+    ///
+    /// assert_eq!(ArcShortStr::EMPTY.is_empty(), true);
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.repr.is_empty()
+    }
+
+    #[inline(always)]
+    /// Inline `value` if it fits, or otherwise take it as an [`Arc<str>`], which only allocates
+    /// if `value` isn't one already.
+    fn inline_or_share<S: AsRef<str> + Into<Arc<str>>>(value: S) -> Self {
+        match ShortStr::from_str(value.as_ref()).try_into_static() {
+            Some(repr) => ArcShortStr { repr },
+            None => {
+                let value: Arc<str> = value.into();
+                let len = value.len();
+                let ptr = Arc::into_raw(value);
+                // safety:
+                // the count is owned by the ArcShortStr until dropped, and isn't inlined
+                let repr = unsafe { ShortStr::from_raw_parts(ptr.cast::<u8>(), len) };
+                ArcShortStr { repr }
+            }
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the pointer to the shared [`str`] as it was produced by [`Arc::into_raw`].
+    fn as_raw(&self) -> *const str {
+        slice_from_raw_parts(self.repr.ptr, self.repr.len()) as *const str
+    }
+}
+
+impl Drop for ArcShortStr {
+    fn drop(&mut self) {
+        if self.repr.is_str() {
+            // safety:
+            // facades of an ArcShortStr are always created from Arc::into_raw in
+            // inline_or_share, with the pointer stored as is, and each ArcShortStr holds one count
+            drop(unsafe { Arc::from_raw(self.as_raw()) });
+        }
+    }
+}
+
+impl Clone for ArcShortStr {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        if self.repr.is_str() {
+            // safety:
+            // see Drop, the new count is owned by the clone
+            unsafe { Arc::increment_strong_count(self.as_raw()) };
+        }
+        ArcShortStr { repr: self.repr }
+    }
+}
+
+impl From<&str> for ArcShortStr {
+    #[inline(always)]
+    fn from(value: &str) -> Self {
+        Self::inline_or_share(value)
+    }
+}
+
+impl From<ShortStr<'_>> for ArcShortStr {
+    #[inline(always)]
+    fn from(value: ShortStr<'_>) -> Self {
+        Self::inline_or_share(value.as_str())
+    }
+}
+
+impl From<Arc<str>> for ArcShortStr {
+    #[inline(always)]
+    fn from(value: Arc<str>) -> Self {
+        Self::inline_or_share(value)
+    }
+}
+
+crate::impl_str_like!(ArcShortStr);
@@ -0,0 +1,136 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+    mem::MaybeUninit,
+    ptr::{copy_nonoverlapping, NonNull},
+    slice, str,
+};
+
+// Size of the first chunk, later chunks double in size up to MAX_CHUNK_SIZE.
+const CHUNK_SIZE: usize = 1024;
+const MAX_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// A bump arena for strings, handing out [`&str`] copies that live as long as the [`Arena`] is
+/// borrowed. Strings are copied into large chunks which are only freed when dropping the
+/// [`Arena`], see [`ShortStr::from_str_in`](crate::ShortStr::from_str_in).
+///
+/// # Examples
+/// These examples assume a little endian 64-bit architecture.
+/// ```
+/// use short_str::{Arena, ShortStr};
+///
+/// let arena = Arena::new();
+/// let built = format!("{}/{}", "some/rather/long", "path");
+/// let path = ShortStr::from_str_in(&built, &arena);
+/// drop(built);
+/// assert_eq!(path, "some/rather/long/path");
+/// ```
+pub struct Arena {
+    // owned chunks, only kept as raw pointers so that handing out &str into them never aliases
+    // a Box or a &mut
+    chunks: RefCell<Vec<NonNull<[MaybeUninit<u8>]>>>,
+    // bytes used of the last chunk
+    used: Cell<usize>,
+}
+
+impl Arena {
+    #[inline(always)]
+    /// Produce an empty [`Arena`], which doesn't allocate until a string is copied into it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use short_str::Arena;
+    ///
+    /// let arena = Arena::new();
+    /// assert_eq!(arena.alloc_str("hello"), "hello");
+    /// ```
+    pub const fn new() -> Self {
+        Arena {
+            chunks: RefCell::new(Vec::new()),
+            used: Cell::new(0),
+        }
+    }
+
+    /// Copy `string` into the [`Arena`], regardless of its length.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::Arena;
+    ///
+    /// let arena = Arena::new();
+    /// let string = String::from("hello");
+    /// let copy: &str = arena.alloc_str(&string);
+    /// drop(string);
+    /// assert_eq!(copy, "hello");
+    /// ```
+    pub fn alloc_str(&self, string: &str) -> &str {
+        if string.is_empty() {
+            return "";
+        }
+
+        let mut chunks = self.chunks.borrow_mut();
+        let free = match chunks.last() {
+            Some(chunk) => chunk.len() - self.used.get(),
+            None => 0,
+        };
+
+        if free < string.len() {
+            let size = match chunks.last() {
+                Some(chunk) => (chunk.len() * 2).min(MAX_CHUNK_SIZE),
+                None => CHUNK_SIZE,
+            };
+            let chunk = Box::<[u8]>::new_uninit_slice(size.max(string.len()));
+            // safety:
+            // Box::into_raw never produces a null pointer
+            chunks.push(unsafe { NonNull::new_unchecked(Box::into_raw(chunk)) });
+            self.used.set(0);
+        }
+
+        // safety:
+        // a chunk with enough free bytes was pushed if the last one didn't have them
+        let chunk = unsafe { chunks.last().unwrap_unchecked() };
+        // safety:
+        // used never exceeds the length of the last chunk
+        let start = unsafe { chunk.cast::<u8>().as_ptr().add(self.used.get()) };
+        self.used.set(self.used.get() + string.len());
+        // safety:
+        // the bytes after used are free and never handed out before, and aren't freed until the
+        // arena is dropped, which the returned &str can't outlive
+        unsafe {
+            copy_nonoverlapping(string.as_ptr(), start, string.len());
+            str::from_utf8_unchecked(slice::from_raw_parts(start, string.len()))
+        }
+    }
+}
+
+impl Default for Arena {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for Arena {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Arena")
+            .field("chunks", &self.chunks.borrow().len())
+            .field("used", &self.used.get())
+            .finish()
+    }
+}
+
+impl Drop for Arena {
+    fn drop(&mut self) {
+        for chunk in self.chunks.get_mut().drain(..) {
+            // safety:
+            // the chunk was produced by Box::into_raw, and nothing borrows the arena anymore
+            drop(unsafe { Box::from_raw(chunk.as_ptr()) });
+        }
+    }
+}
+
+// safety:
+// the arena owns its chunks like a Vec<Box<[u8]>> would, RefCell and Cell keep it !Sync
+unsafe impl Send for Arena {}
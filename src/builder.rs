@@ -0,0 +1,219 @@
+use core::fmt::{Arguments, Debug, Write};
+
+use crate::{
+    CapacityError, InlineStr, ShortStr, BYTE_SIZE, INLINE_BYTE_SIZE, INLINE_FLAG, MARKER_INDEX,
+};
+
+/// A buffer for building an inlined [`ShortStr`] piece by piece, e.g. through [`write!`] as it
+/// implements [`Write`]. The bytes are written directly into the layout of an inlined
+/// [`ShortStr`], so finishing only sets the marker.
+///
+/// Writing more than [`INLINE_BYTE_SIZE`] bytes fails, see [`short_format!`](crate::short_format)
+/// for formatting in one go.
+///
+/// # Examples
+/// These examples assume a little endian 64-bit architecture.
+/// ```
+/// use core::fmt::Write;
+/// use short_str::InlineBuilder;
+///
+/// let (name, id) = ("key", 42);
+/// let mut builder = InlineBuilder::new();
+/// write!(builder, "{name}{id}").unwrap();
+/// assert_eq!(builder.finish().unwrap(), "key42");
+///
+/// let mut builder = InlineBuilder::new();
+/// assert!(write!(builder, "{name} {id} is way too long to be inlined").is_err());
+/// assert!(builder.finish().is_err());
+/// ```
+#[derive(Clone, Copy)]
+pub struct InlineBuilder {
+    // layout of an inlined ShortStr, the marker is only set when finishing
+    data: [u8; BYTE_SIZE],
+    len: usize,
+    // length of everything written, including what didn't fit
+    required: usize,
+}
+
+impl InlineBuilder {
+    #[inline(always)]
+    /// Produce an empty [`InlineBuilder`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::InlineBuilder;
+    ///
+    /// assert_eq!(InlineBuilder::new().as_str(), "");
+    /// ```
+    pub const fn new() -> Self {
+        InlineBuilder {
+            data: [0; BYTE_SIZE],
+            len: 0,
+            required: 0,
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the number of bytes written into the [`InlineBuilder`] so far, excluding anything
+    /// that didn't fit.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::InlineBuilder;
+    ///
+    /// let mut builder = InlineBuilder::new();
+    /// builder.push_str("hello").unwrap();
+    /// assert_eq!(builder.len(), 5);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    /// Returns `true` if nothing has been written into the [`InlineBuilder`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::InlineBuilder;
+    ///
+    /// assert_eq!(InlineBuilder::new().is_empty(), true);
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    /// Returns `true` if anything written into the [`InlineBuilder`] didn't fit.
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::InlineBuilder;
+    ///
+    /// let mut builder = InlineBuilder::new();
+    /// assert!(builder.push_str("way too long to be inlined").is_err());
+    /// assert_eq!(builder.is_overflowed(), true);
+    /// ```
+    pub const fn is_overflowed(&self) -> bool {
+        self.required > INLINE_BYTE_SIZE
+    }
+
+    #[inline(always)]
+    /// View the bytes written so far as a [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::InlineBuilder;
+    ///
+    /// let mut builder = InlineBuilder::new();
+    /// builder.push_str("hello").unwrap();
+    /// assert_eq!(builder.as_str(), "hello");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        let (bytes, _) = self.data.split_at(self.len);
+        // safety:
+        // only whole &str are ever written into data
+        unsafe { core::str::from_utf8_unchecked(bytes) }
+    }
+
+    /// Append `string` to the [`InlineBuilder`].
+    ///
+    /// # Errors
+    /// [`CapacityError`] with the length of everything written so far, including `string`, if it
+    /// doesn't fit. Nothing is appended in that case, and any later write fails as well.
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::{CapacityError, InlineBuilder};
+    ///
+    /// let mut builder = InlineBuilder::new();
+    /// assert_eq!(builder.push_str("hello, "), Ok(()));
+    /// assert_eq!(builder.push_str("world!!!!"), Err(CapacityError { len: 16 }));
+    /// assert_eq!(builder.as_str(), "hello, ");
+    /// ```
+    pub fn push_str(&mut self, string: &str) -> Result<(), CapacityError> {
+        self.required = self.required.saturating_add(string.len());
+        if self.is_overflowed() {
+            return Err(CapacityError { len: self.required });
+        }
+
+        self.data[self.len..self.required].copy_from_slice(string.as_bytes());
+        self.len = self.required;
+        Ok(())
+    }
+
+    /// Produce the [`InlineStr`] of everything written into the [`InlineBuilder`].
+    ///
+    /// # Errors
+    /// [`CapacityError`] if anything written didn't fit.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::InlineBuilder;
+    ///
+    /// let mut builder = InlineBuilder::new();
+    /// builder.push_str("hello").unwrap();
+    /// assert_eq!(builder.finish().unwrap(), "hello");
+    /// ```
+    pub fn finish(self) -> Result<InlineStr, CapacityError> {
+        if self.is_overflowed() {
+            return Err(CapacityError { len: self.required });
+        }
+        if self.is_empty() {
+            return Ok(InlineStr::EMPTY);
+        }
+
+        let mut data = self.data;
+        data[MARKER_INDEX] = INLINE_FLAG | self.len as u8;
+        // safety:
+        // the marker is set, and the length fits inline
+        let short = unsafe { ShortStr::from_bytes(data) };
+        InlineStr::try_from(short)
+    }
+
+    /// Format `args` into an [`InlineStr`], used by [`short_format!`](crate::short_format).
+    ///
+    /// # Errors
+    /// [`CapacityError`] if the formatted string doesn't fit.
+    ///
+    /// # Panics
+    /// If a formatting trait implementation returns an error on its own, like
+    /// [`format!`](https://doc.rust-lang.org/std/macro.format.html) does.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::InlineBuilder;
+    ///
+    /// let id = 7;
+    /// let key = InlineBuilder::format(format_args!("id{id}")).unwrap();
+    /// assert_eq!(key, "id7");
+    /// ```
+    pub fn format(args: Arguments<'_>) -> Result<InlineStr, CapacityError> {
+        let mut builder = Self::new();
+        if builder.write_fmt(args).is_err() && !builder.is_overflowed() {
+            panic!("a formatting trait implementation returned an error");
+        }
+        builder.finish()
+    }
+}
+
+impl Default for InlineBuilder {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for InlineBuilder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl Write for InlineBuilder {
+    #[inline(always)]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.push_str(s).map_err(|_| core::fmt::Error)
+    }
+}
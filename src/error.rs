@@ -0,0 +1,97 @@
+use const_panic::concat_panic;
+use core::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use crate::INLINE_BYTE_SIZE;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The reason a checked slicing operation on a [`ShortStr`](crate::ShortStr) failed, see
+/// [`ShortStr::try_slice`](crate::ShortStr::try_slice).
+///
+/// # Examples
+/// ```
+/// use short_str::{ShortStr, SliceError};
+///
+/// let string = ShortStr::from("abc");
+/// assert_eq!(string.try_slice(2..1), Err(SliceError::Descending { start: 2, end: 1 }));
+/// ```
+pub enum SliceError {
+    /// An index of the range is past the end, `len` being the length of the sliced value.
+    OutOfBounds { index: usize, len: usize },
+    /// The start of the range is after its end.
+    Descending { start: usize, end: usize },
+    /// An index of the range is inside of a grapheme.
+    NotCharBoundary { index: usize },
+    /// An excluded start or included end of the range is `usize::MAX`, so the range can't be
+    /// represented as an exclusive [`Range`](core::ops::Range).
+    Overflow,
+}
+
+impl Display for SliceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SliceError::OutOfBounds { index, len } => {
+                write!(f, "byte index {index} is out of bounds of length {len}")
+            }
+            SliceError::Descending { start, end } => {
+                write!(f, "begin <= end ({start} <= {end}) when slicing")
+            }
+            SliceError::NotCharBoundary { index } => {
+                write!(f, "byte index {index} is not a char boundary")
+            }
+            SliceError::Overflow => write!(f, "attempted to index str past maximum usize"),
+        }
+    }
+}
+
+impl Error for SliceError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A string couldn't be inlined as it's longer than [`INLINE_BYTE_SIZE`], see
+/// [`InlineStr`](crate::InlineStr).
+///
+/// # Examples
+/// These examples assume a little endian 64-bit architecture.
+/// ```
+/// use short_str::{CapacityError, InlineStr};
+///
+/// let error = InlineStr::try_from("this string is too long to be inlined");
+/// assert_eq!(error, Err(CapacityError { len: 37 }));
+/// ```
+pub struct CapacityError {
+    /// The length of the string that didn't fit, or for an
+    /// [`InlineBuilder`](crate::InlineBuilder) the length written by the time it didn't fit.
+    pub len: usize,
+}
+
+impl Display for CapacityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "string of length {} exceeds the inline capacity of {INLINE_BYTE_SIZE} bytes",
+            self.len
+        )
+    }
+}
+
+impl Error for CapacityError {}
+
+impl SliceError {
+    /// Panics with the same message as [`Display`], but usable in const contexts.
+    pub(crate) const fn panic(self) -> ! {
+        match self {
+            SliceError::OutOfBounds { index, len } => {
+                concat_panic!("byte index ", index, " is out of bounds of length ", len)
+            }
+            SliceError::Descending { start, end } => {
+                concat_panic!("begin <= end (", start, " <= ", end, ") when slicing")
+            }
+            SliceError::NotCharBoundary { index } => {
+                concat_panic!("byte index ", index, " is not a char boundary")
+            }
+            SliceError::Overflow => panic!("attempted to index str past maximum usize"),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use core::str::FromStr;
+
+use crate::{CapacityError, ShortStr};
+
+/// A [`ShortStr`] that is always inlined, and thus owns its bytes without any lifetime. Holds at
+/// most [`INLINE_BYTE_SIZE`](crate::INLINE_BYTE_SIZE) bytes, with the same layout as an inlined
+/// [`ShortStr`].
+///
+/// # Examples
+/// These examples assume a little endian 64-bit architecture.
+/// ```
+/// use short_str::{InlineStr, ShortStr};
+///
+/// let inlined = InlineStr::try_from("hello").unwrap();
+/// let short: ShortStr = inlined.into();
+/// assert_eq!(short, "hello");
+/// assert!(InlineStr::try_from("stronger, faster, better, morer?").is_err());
+/// ```
+#[derive(Clone, Copy)]
+pub struct InlineStr {
+    // never a facade
+    repr: ShortStr<'static>,
+}
+
+impl InlineStr {
+    /// An empty [`InlineStr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use short_str::InlineStr;
+    ///
+    /// assert_eq!(InlineStr::EMPTY, "");
+    /// ```
+    pub const EMPTY: InlineStr = InlineStr { repr: ShortStr::EMPTY };
+
+    #[inline(always)]
+    /// Produce an [`InlineStr`] from a [`&str`], copying its bytes.
+    ///
+    /// # Errors
+    /// [`CapacityError`] if `value` is longer than [`INLINE_BYTE_SIZE`](crate::INLINE_BYTE_SIZE).
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::{CapacityError, InlineStr};
+    ///
+    /// const KEY: Result<InlineStr, CapacityError> = InlineStr::try_from_str("key");
+    /// assert_eq!(KEY.unwrap(), "key");
+    /// ```
+    pub const fn try_from_str(value: &str) -> Result<Self, CapacityError> {
+        match ShortStr::from_str(value).try_into_static() {
+            Some(repr) => Ok(InlineStr { repr }),
+            None => Err(CapacityError { len: value.len() }),
+        }
+    }
+
+    #[inline(always)]
+    /// Convert the [`InlineStr`] into a [`ShortStr`] of any lifetime, which is always inlined.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{InlineStr, ShortStr};
+    ///
+    /// let inlined = InlineStr::try_from("hello").unwrap();
+    /// let short: ShortStr<'static> = inlined.as_short_str();
+    /// assert_eq!(short.is_str(), false);
+    /// ```
+    pub const fn as_short_str<'str_lt>(self) -> ShortStr<'str_lt> {
+        self.repr
+    }
+
+    #[inline(always)]
+    /// View the [`InlineStr`] as a [`&str`] borrowed from `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::InlineStr;
+    ///
+    /// let inlined = InlineStr::try_from("hello").unwrap();
+    /// assert_eq!(inlined.as_str(), "hello");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        self.repr.as_str()
+    }
+
+    #[inline(always)]
+    /// Returns the number of bytes in the [`InlineStr`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::InlineStr;
+    ///
+    /// assert_eq!(InlineStr::try_from("hello").unwrap().len(), 5);
+    /// ```
+    pub const fn len(self) -> usize {
+        self.repr.len()
+    }
+
+    #[inline(always)]
+    /// Returns `true` if the [`InlineStr`] has a length of zero bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::InlineStr;
+    ///
+    /// assert_eq!(InlineStr::EMPTY.is_empty(), true);
+    /// ```
+    pub const fn is_empty(self) -> bool {
+        self.repr.is_empty()
+    }
+}
+
+impl TryFrom<&str> for InlineStr {
+    type Error = CapacityError;
+
+    #[inline(always)]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(value)
+    }
+}
+
+impl TryFrom<ShortStr<'_>> for InlineStr {
+    type Error = CapacityError;
+
+    #[inline(always)]
+    fn try_from(value: ShortStr<'_>) -> Result<Self, Self::Error> {
+        // facades can still be short enough when made through from_str_unchecked
+        Self::try_from_str(value.as_str())
+    }
+}
+
+impl FromStr for InlineStr {
+    type Err = CapacityError;
+
+    #[inline(always)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<'str_lt> From<InlineStr> for ShortStr<'str_lt> {
+    #[inline(always)]
+    fn from(value: InlineStr) -> Self {
+        value.as_short_str()
+    }
+}
+
+crate::impl_str_like!(InlineStr);
@@ -0,0 +1,141 @@
+use alloc::collections::BTreeSet;
+use core::{cell::RefCell, fmt::Debug, mem::transmute};
+
+use crate::{Arena, ShortStr};
+
+/// A string interner handing out [`ShortStr`] values that live as long as the [`Interner`] is
+/// borrowed. Strings that can be inlined are returned inlined and never stored, while longer
+/// strings are copied into an [`Arena`] once and returned as facades of that single copy.
+///
+/// As every value of an [`Interner`] is either inlined or a facade of its one copy,
+/// [`ShortStr::scalar_eq`] is a correct and constant time equality for them.
+///
+/// # Examples
+/// These examples assume a little endian 64-bit architecture.
+/// ```
+/// use short_str::Interner;
+///
+/// let interner = Interner::new();
+/// let ident = interner.intern("ident");
+/// let long = interner.intern(&String::from("a_rather_long_identifier"));
+/// assert_eq!(ident.is_str(), false);
+/// assert_eq!(long.is_str(), true);
+/// assert!(long.scalar_eq(interner.intern("a_rather_long_identifier")));
+/// assert_eq!(interner.len(), 1);
+/// ```
+pub struct Interner {
+    arena: Arena,
+    // facades into the arena, 'static standing in for the lifetime of the interner
+    interned: RefCell<BTreeSet<ShortStr<'static>>>,
+}
+
+impl Interner {
+    #[inline(always)]
+    /// Produce an empty [`Interner`], which doesn't allocate until a string that can't be
+    /// inlined is interned.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::Interner;
+    ///
+    /// assert_eq!(Interner::new().len(), 0);
+    /// ```
+    pub const fn new() -> Self {
+        Interner {
+            arena: Arena::new(),
+            interned: RefCell::new(BTreeSet::new()),
+        }
+    }
+
+    /// Intern `string`, producing an inlined [`ShortStr`] if it fits, or otherwise a facade of
+    /// the copy of `string` in the [`Interner`], copying it on the first call.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::Interner;
+    ///
+    /// let interner = Interner::new();
+    /// let first = interner.intern("this string is too long to be inlined");
+    /// let second = interner.intern(&String::from("this string is too long to be inlined"));
+    /// assert_eq!(first.as_ptr(), second.as_ptr());
+    /// ```
+    pub fn intern<'arena>(&'arena self, string: &str) -> ShortStr<'arena> {
+        if let Some(interned) = self.get(string) {
+            return interned;
+        }
+
+        let copy = ShortStr::from_str_in(string, &self.arena);
+        // safety:
+        // the copy lives as long as the arena, which is only dropped along with the interner
+        let copy = unsafe { transmute::<ShortStr<'arena>, ShortStr<'static>>(copy) };
+        self.interned.borrow_mut().insert(copy);
+        copy
+    }
+
+    /// Produce the interned [`ShortStr`] of `string` without interning it. Strings that can be
+    /// inlined are always produced, inlined, while longer strings are only produced if they have
+    /// been interned before.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::Interner;
+    ///
+    /// let interner = Interner::new();
+    /// let long = "this string is too long to be inlined";
+    /// assert_eq!(interner.get("short"), Some("short".into()));
+    /// assert_eq!(interner.get(long), None);
+    /// let interned = interner.intern(long);
+    /// assert!(interner.get(long).unwrap().scalar_eq(interned));
+    /// ```
+    pub fn get<'arena>(&'arena self, string: &str) -> Option<ShortStr<'arena>> {
+        match ShortStr::from_str(string).try_into_static() {
+            Some(inlined) => Some(inlined),
+            None => self.interned.borrow().get(string).copied(),
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the number of strings copied into the [`Interner`], which excludes inlined ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::Interner;
+    ///
+    /// let interner = Interner::new();
+    /// interner.intern("short");
+    /// interner.intern("this string is too long to be inlined");
+    /// interner.intern("this string is too long to be inlined");
+    /// assert_eq!(interner.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.interned.borrow().len()
+    }
+
+    #[inline(always)]
+    /// Returns `true` if no string has been copied into the [`Interner`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::Interner;
+    ///
+    /// let interner = Interner::new();
+    /// interner.intern("short");
+    /// assert_eq!(interner.is_empty(), true);
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.interned.borrow().is_empty()
+    }
+}
+
+impl Default for Interner {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for Interner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.interned.borrow().iter()).finish()
+    }
+}
@@ -48,31 +48,128 @@
 //!
 //! # Features and Guarantuees
 //! - Size
-//!     - Equal size to `&str`
+//!     - Equal size to `&str`, also for `Option<ShortStr>`
 //!     - Little endian size optimization (Use MSG of length portion, statically asserted)
+//!     - Big endian support (Only pointer bytes as storage)
 //! - Safety
 //!     - Assumptions are asserted at compile-time
 //!     - Immutable data
 //! - Usage/Ergonomics
 //!     - Slicing
 //!         - Dedicated slicing functions
+//!         - Const slicing through `slice_range`, for computing slices at compile-time
+//!         - `Index` on all range types, identical to `&str`
+//!         - Panics identical to `&str`, or `SliceError`s through `try_slice`
 //!     - `ShortStr` and `&str` comparison
-//!         - Scalar comparison between `ShortStr`
+//!         - Scalar comparison between inlined `ShortStr`
+//!         - Byte comparison when either side is a `&str` facade
 //!         - Comparison on `&str` via cast (Copies on inlinable `&str`)
+//!     - Ordering identical to `&str`, as a scalar comparison between inlined `ShortStr`
+//!     - `Hash` identical to `&str`, with `ScalarHash` as a faster opt-in for inlined `ShortStr`
 //!     - `Deref` to `str`
+//!     - Compile-time construction through `short_str!`, or `inline_str!` to require inlining
+//!     - Always inlined `InlineStr` without a lifetime, failing with a `CapacityError` otherwise
+//!     - Formatting without allocating through `short_format!` and `InlineBuilder`
+//!     - Concatenation into an inlined `ShortStr` through `try_concat`, or `concat` falling back
+//!       to an owned `ShortString`
+//!     - Splitting into `ShortStr`s with the lifetime of the original `&str` (`split`,
+//!       `split_once`, `rsplit_once`, `lines`, `split_whitespace`)
+//!     - Trimming into `ShortStr`s with the lifetime of the original `&str` (`trim`,
+//!       `trim_matches`, `strip_prefix`, `strip_suffix`, ...)
+//!     - Building `ShortStr`s that outlive their source through `from_str_in`, copying long
+//!       strings into a bump `Arena`
+//!     - A packed `ShortStrVec` column, comparing inlined values as integers in bulk
+//!       (`contains`, `dedup`)
+//!     - A `ShortStrMap` hashing and comparing inlined keys as integers, looked up by `&str`
+//!       without allocating
+//!     - Interning through `Interner`, inlining short strings and copying long strings once, so
+//!       that `scalar_eq` is an identity comparison
+//!
+//! # Cargo features
+//! - `pointer-storage`: only use the pointer bytes to store inlined data and mark inlined values
+//!   through the most significant bit of the length, which is never set for a `&str`. This
+//!   removes any assumption on the length of a `&str` at the cost of the inline capacity (8
+//!   instead of 15 bytes on 64-bit).
+//!
+//! - `alloc`: owned `ShortString` and shared `ArcShortStr` that only allocate when the content
+//!   can't be inlined, the bump `Arena`, the `Interner`, and the `ShortStrVec` and `ShortStrMap`
+//!   collections.
+//!
+//! - `inline-offset`: store the offset of the inlined bytes in the marker next to the length, so
+//!   slicing an inlined value only rewrites the marker. The bytes are normalized lazily when
+//!   comparing or hashing, or through [`ShortStr::normalize`].
+//!
+//! - `eager-normalization`: with `inline-offset`, normalize the inlined bytes right after slicing
+//!   through the offset, so comparing and hashing never have to.
+//!
+//! - `serde`: serialize [`ShortStr`] as a string, and deserialize it by borrowing from the input.
+//!   Strings that can't be borrowed (e.g. read from a stream) are only accepted if they can be
+//!   inlined.
 //!
 //! More details may be found at the [repository][https://github.com/Tobiky/short-str].
 
 #![no_std]
 use core::{
+    borrow::Borrow,
+    cmp::Ordering,
     convert::Infallible,
     fmt::{Debug, Display},
+    hash::{Hash, Hasher},
     marker::PhantomData,
     mem::transmute,
-    ops::{Deref, Range, RangeBounds},
+    num::NonZeroUsize,
+    ops::{
+        Bound, Deref, Index, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+        RangeToInclusive,
+    },
     ptr::copy_nonoverlapping,
 };
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod builder;
+mod error;
+mod inline;
+mod macros;
+mod split;
+pub use builder::InlineBuilder;
+pub use error::{CapacityError, SliceError};
+pub use inline::InlineStr;
+pub use split::{Lines, ShortPattern, Split, SplitWhitespace};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use const_panic::concat_assert;
+}
+
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "alloc")]
+mod arc;
+#[cfg(feature = "alloc")]
+mod arena;
+#[cfg(feature = "alloc")]
+mod interner;
+#[cfg(feature = "alloc")]
+mod map;
+#[cfg(feature = "alloc")]
+mod string;
+#[cfg(feature = "alloc")]
+mod vec;
+#[cfg(feature = "alloc")]
+pub use arc::ArcShortStr;
+#[cfg(feature = "alloc")]
+pub use arena::Arena;
+#[cfg(feature = "alloc")]
+pub use interner::Interner;
+#[cfg(feature = "alloc")]
+pub use map::{ShortStrMap, ShortStrMapIter};
+#[cfg(feature = "alloc")]
+pub use string::ShortString;
+#[cfg(feature = "alloc")]
+pub use vec::{ShortStrVec, ShortStrVecIter};
+
 #[cfg(test)]
 mod tests;
 
@@ -80,14 +177,54 @@ mod tests;
 const _: () = const {
     use const_panic::concat_assert;
 
-    const REPO_URL: &'static str = "https://github.com/Tobiky/short-str";
+    const REPO_URL: &str = "https://github.com/Tobiky/short-str";
 
-    // Little Endian
+    // Length marker is the most significant byte of the length, which is the last byte of the
+    // length on little endian and the first on big endian
     // Not supported by miri and #[ignore] is only on functions
     #[cfg(not(miri))]
     concat_assert!(
-        unsafe { transmute::<&str, [u8; BYTE_SIZE]>("test") }[PTR_SIZE] as usize == "test".len(),
-        "big endian architecture is currently unsupported for ShortStr's",
+        unsafe { transmute::<&str, [u8; BYTE_SIZE]>("test") }[LEN_LSB_INDEX] as usize
+            == "test".len(),
+        "expected the least significant byte of the length of &str at index ",
+        LEN_LSB_INDEX,
+        ", please file an issue at ",
+        REPO_URL
+    );
+
+    // Inlined bytes can't overlap the marker
+    concat_assert!(
+        INLINE_BYTE_SIZE <= MARKER_INDEX && MARKER_INDEX < BYTE_SIZE,
+        "expected the marker (index ",
+        MARKER_INDEX,
+        ") to be after the inlined bytes (",
+        INLINE_BYTE_SIZE,
+        " bytes), please file an issue at ",
+        REPO_URL
+    );
+
+    // Offsets have to fit in the marker besides the length and the flag
+    #[cfg(feature = "inline-offset")]
+    concat_assert!(
+        INLINE_BYTE_SIZE <= LEN_BITS as usize
+            && ((INLINE_BYTE_SIZE - 1) << OFFSET_SHIFT) & (INLINE_FLAG as usize | !0xff) == 0,
+        "expected the inline offset (up to ",
+        INLINE_BYTE_SIZE - 1,
+        ") and length (up to ",
+        INLINE_BYTE_SIZE,
+        ") to fit in the marker, please file an issue at ",
+        REPO_URL
+    );
+
+    // Niche for Option<ShortStr>
+    concat_assert!(
+        size_of::<Option<ShortStr>>() == size_of::<&str>(),
+        "expected Option<ShortStr> to have the same size as &str (",
+        size_of::<Option<ShortStr>>(),
+        " vs. ",
+        size_of::<&str>(),
+        "), please file an issue at ",
+        REPO_URL
     );
 
     // &str Size
@@ -121,7 +258,56 @@ const _: () = const {
 const PTR_SIZE: usize = size_of::<usize>();
 const LEN_SIZE: usize = size_of::<usize>();
 const BYTE_SIZE: usize = PTR_SIZE + LEN_SIZE;
-const INLINE_BYTE_SIZE: usize = BYTE_SIZE - 1;
+
+// The length marker is the most significant byte of the length.
+// Little endian: the last byte, inlined data uses everything before it (pointer and length bytes).
+// Big endian: the first byte of the length, inlined data uses the pointer bytes before it.
+// pointer-storage: same marker, but inlined data only uses the pointer bytes on either endian.
+#[cfg(target_endian = "little")]
+const MARKER_INDEX: usize = BYTE_SIZE - 1;
+#[cfg(target_endian = "big")]
+const MARKER_INDEX: usize = PTR_SIZE;
+/// The maximum amount of bytes an inlined [`ShortStr`] can hold, e.g. 15 bytes on little endian
+/// 64-bit architectures.
+#[cfg(not(any(target_endian = "big", feature = "pointer-storage")))]
+pub const INLINE_BYTE_SIZE: usize = BYTE_SIZE - 1;
+/// The maximum amount of bytes an inlined [`ShortStr`] can hold, e.g. 8 bytes on 64-bit
+/// architectures.
+#[cfg(any(target_endian = "big", feature = "pointer-storage"))]
+pub const INLINE_BYTE_SIZE: usize = PTR_SIZE;
+
+// Set in the marker of every inlined value.
+// pointer-storage: the most significant bit of the length, which is never set for a &str as no
+// allocation may be larger than isize::MAX bytes. This leaves the length of any &str intact,
+// instead of assuming that the whole most significant byte is unused.
+#[cfg(feature = "pointer-storage")]
+const INLINE_FLAG: u8 = 0x80;
+#[cfg(not(feature = "pointer-storage"))]
+const INLINE_FLAG: u8 = 0x00;
+// Bits of the marker that are all unset for a &str facade.
+#[cfg(feature = "pointer-storage")]
+const FACADE_MASK: u8 = INLINE_FLAG;
+#[cfg(not(feature = "pointer-storage"))]
+const FACADE_MASK: u8 = 0xff;
+const EMPTY_MARKER: u8 = -1i8 as u8;
+// Bits of the marker holding the length of an inlined value.
+// inline-offset: the lower nibble, the upper bits (besides INLINE_FLAG) hold the offset of the
+// first inlined byte, which lets slicing skip moving the bytes. As the offset and length never
+// add up to more than INLINE_BYTE_SIZE (<= 15) the marker is never mistaken for EMPTY_MARKER.
+#[cfg(feature = "inline-offset")]
+const LEN_BITS: u8 = 0x0f;
+#[cfg(not(feature = "inline-offset"))]
+const LEN_BITS: u8 = !INLINE_FLAG;
+const OFFSET_SHIFT: u32 = LEN_BITS.count_ones();
+// Whether inlined values may hold an offset or unused bytes that aren't zeroed after slicing,
+// in which case they have to be normalized before any scalar operation. eager-normalization
+// still slices through the offset, but normalizes right after, so values are never left with one.
+const LAZY_NORMALIZATION: bool =
+    cfg!(all(feature = "inline-offset", not(feature = "eager-normalization")));
+#[cfg(all(debug_assertions, target_endian = "little"))]
+const LEN_LSB_INDEX: usize = PTR_SIZE;
+#[cfg(all(debug_assertions, target_endian = "big"))]
+const LEN_LSB_INDEX: usize = BYTE_SIZE - 1;
 
 #[cfg(target_pointer_width = "64")]
 type CoveringInt = u128;
@@ -130,13 +316,68 @@ type CoveringInt = u64;
 #[cfg(target_pointer_width = "16")]
 type CoveringInt = u32;
 
-// Size is MSB for little endian
-const SIZE_MASK: CoveringInt = (0xff as CoveringInt).rotate_right(8);
-const DATA_MASK: CoveringInt = !SIZE_MASK;
+// Size is MSB for little endian, and somewhere in the middle for big endian
+const SIZE_MASK: CoveringInt = (0xff as CoveringInt) << byte_shift(MARKER_INDEX);
+const DATA_MASK: CoveringInt = prefix_mask(INLINE_BYTE_SIZE);
+
+/// Returns the amount of bits the byte at `index` in the data is shifted by in a [`CoveringInt`]
+/// made through [`CoveringInt::from_ne_bytes`].
+#[inline(always)]
+const fn byte_shift(index: usize) -> u32 {
+    if cfg!(target_endian = "little") {
+        (index * 8) as u32
+    } else {
+        ((BYTE_SIZE - 1 - index) * 8) as u32
+    }
+}
+
+/// Returns the mask of all bytes in the data before `index` in a [`CoveringInt`] made through
+/// [`CoveringInt::from_ne_bytes`].
+#[inline(always)]
+const fn prefix_mask(index: usize) -> CoveringInt {
+    let shift = (index * 8) as u32;
+    // the lower bytes on little endian and the upper bytes on big endian
+    let rest = if cfg!(target_endian = "little") {
+        CoveringInt::MAX.checked_shl(shift)
+    } else {
+        CoveringInt::MAX.checked_shr(shift)
+    };
+    match rest {
+        Some(rest) => !rest,
+        None => CoveringInt::MAX,
+    }
+}
+
+/// Moves all bytes in `int` `count` bytes towards the start of the data, where `int` is a
+/// [`CoveringInt`] made through [`CoveringInt::from_ne_bytes`].
+#[inline(always)]
+const fn shift_to_start(int: CoveringInt, count: usize) -> CoveringInt {
+    if cfg!(target_endian = "little") {
+        int >> (count * 8)
+    } else {
+        int << (count * 8)
+    }
+}
+
+/// Moves all bytes in `int` `count` bytes towards the end of the data, where `int` is a
+/// [`CoveringInt`] made through [`CoveringInt::from_ne_bytes`].
+#[inline(always)]
+const fn shift_from_start(int: CoveringInt, count: usize) -> CoveringInt {
+    if cfg!(target_endian = "little") {
+        int << (count * 8)
+    } else {
+        int >> (count * 8)
+    }
+}
 
 // layout of &str is ptr, len
 // see `verify_layout` test
-#[derive(Clone, Copy, Eq, PartialOrd, Ord)]
+// ptr:  a pointer so that const facades and provenance survive, inlined bytes are stored here as a
+//       pointer without provenance
+// len:  never zero as the marker is always set for inlined values and facades are never empty,
+//       giving Option<ShortStr> a niche
+#[derive(Clone, Copy, Eq)]
+#[repr(C)]
 /// An almost drop-in replacement for [`&str`]. See crate level documentation for more information.
 ///
 /// # Examples
@@ -147,11 +388,17 @@ const DATA_MASK: CoveringInt = !SIZE_MASK;
 /// let not_inlined = ShortStr::from("stronger, faster, better, morer?");
 /// ```
 pub struct ShortStr<'str_lt> {
+    ptr: *const u8,
+    len: NonZeroUsize,
     _lt: PhantomData<&'str_lt Infallible>,
-    data: [u8; BYTE_SIZE],
 }
 pub type ShStr<'str_lt> = ShortStr<'str_lt>;
 
+// safety:
+// a ShortStr is either a &'str_lt str or owns its bytes, both of which are Send and Sync
+unsafe impl Send for ShortStr<'_> {}
+unsafe impl Sync for ShortStr<'_> {}
+
 impl Debug for ShortStr<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self.as_str())
@@ -176,15 +423,14 @@ impl<'str_lt> Variant<'str_lt> {
     const fn from_short_str(value: ShortStr<'str_lt>) -> Self {
         if value.is_str() {
             // Safety:
-            // is_str_ref garantuees that `value` is indeed a &str
-            // ERROR(miri): miri cannot figure out that this is an actual &'str_lt (since it would
-            // have been constructed from that, necessarily)
+            // is_str_ref garantuees that `value` is indeed a &str, and the pointer has kept its
+            // provenance since it was stored as a pointer
             let str_ref = unsafe { transmute::<ShortStr, &'str_lt str>(value) };
             Variant::Facade(str_ref)
         } else if value.is_empty_inlined() {
             Variant::Empty
         } else {
-            Variant::Inlined(value.data)
+            Variant::Inlined(value.to_bytes())
         }
     }
 }
@@ -196,7 +442,7 @@ impl<'str_lt> From<ShortStr<'str_lt>> for Variant<'str_lt> {
     }
 }
 
-/// inline str zero length flag
+// inline str zero length flag
 // useful only with more flags
 // 2 * size_of::<usize>() - 1 = how many bytes that can be stored
 // ilog2 = how many bits to represent size
@@ -216,8 +462,10 @@ impl<'str_lt> ShortStr<'str_lt> {
     /// ```
     pub const EMPTY: ShortStr<'str_lt> = const {
         let mut data = [0; BYTE_SIZE];
-        data[BYTE_SIZE - 1] = -1i8 as u8;
-        ShortStr { data, _lt: PhantomData }
+        data[MARKER_INDEX] = EMPTY_MARKER;
+        // safety:
+        // the marker is set
+        unsafe { ShortStr::from_bytes(data) }
     };
 
     #[inline(always)]
@@ -251,14 +499,80 @@ impl<'str_lt> ShortStr<'str_lt> {
     /// assert_eq!(string.length_marker, "hello".len());
     /// ```
     const fn length_marker(self) -> u8 {
-        // assumptions: little endian
-
         // ------------------------------------------------------------------------------
-        // in the case of little endian the last byte is unrealistic to be set
+        // the most significant byte of the length is unrealistic to be set
         // as that would require more than e.g. 2^58 "directly" addressed bytes of memory
         // and therefore can be used for the inline str mode size, and as a marker.
+        // little endian: last byte of the data
+        // big endian:    first byte of the length, right after the pointer
+        // with pointer-storage only the most significant bit is used as a flag (INLINE_FLAG),
+        // which is garantueed to be unset for any &str
         // ------------------------------------------------------------------------------
-        self.data[BYTE_SIZE - 1]
+        // read through the length rather than the bytes as pointer bytes can't be read in const
+        (self.len.get() >> (usize::BITS - 8)) as u8
+    }
+
+    #[inline(always)]
+    /// Returns the raw bytes of the [`ShortStr`]. Only usable in const contexts for inlined (and
+    /// empty) [`ShortStr`]s, as the bytes of a pointer are unknown at compile-time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("hello");
+    /// assert_eq!(string.to_bytes()[..5], *b"hello");
+    /// ```
+    const fn to_bytes(self) -> [u8; BYTE_SIZE] {
+        // safety:
+        // ShortStr is plain old data of BYTE_SIZE bytes
+        unsafe { transmute::<ShortStr, [u8; BYTE_SIZE]>(self) }
+    }
+
+    #[inline(always)]
+    /// Produce a [`ShortStr`] from its raw bytes.
+    ///
+    /// # Safety
+    /// The length (and thus the marker) in `data` must not be zero, and it must represent either
+    /// an inlined [`ShortStr`] or a [`&str`] living for `'str_lt`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("hello");
+    /// assert_eq!(unsafe { ShortStr::from_bytes(string.to_bytes()) }, string);
+    /// ```
+    const unsafe fn from_bytes(data: [u8; BYTE_SIZE]) -> Self {
+        // safety:
+        // the length is not zero as garantueed by the caller
+        unsafe { transmute::<[u8; BYTE_SIZE], ShortStr>(data) }
+    }
+
+    #[inline(always)]
+    /// Returns the inlined bytes as a big endian integer with the length in place of the marker,
+    /// such that comparing the keys of two inlined [`ShortStr`]s orders them like their
+    /// [`&str`]s. Only meaningful for inlined (and empty) [`ShortStr`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use short_str::ShortStr;
+    ///
+    /// let a = ShortStr::from("ab");
+    /// let b = ShortStr::from("b");
+    /// assert!(a.inline_order_key() < b.inline_order_key());
+    /// ```
+    const fn inline_order_key(self) -> CoveringInt {
+        // the first byte of the data becomes the most significant one and unused bytes are zero,
+        // so when one is a prefix of the other only the length (the marker position) differs
+        // little endian: marker is the least significant byte
+        // big endian:    marker is right after the inlined bytes, followed by zero bytes
+        const MARKER_SHIFT: u32 = ((BYTE_SIZE - 1 - MARKER_INDEX) * 8) as u32;
+        (CoveringInt::from_be_bytes(self.normalize().to_bytes()) & !(0xff << MARKER_SHIFT))
+            | (self.len() as CoveringInt) << MARKER_SHIFT
     }
 
     #[inline(always)]
@@ -279,7 +593,119 @@ impl<'str_lt> ShortStr<'str_lt> {
     /// assert_eq!(string.is_empty_inlined(), true);
     /// ```
     const fn is_empty_inlined(self) -> bool {
-        (self.length_marker() as i8).is_negative()
+        self.length_marker() == EMPTY_MARKER
+    }
+
+    #[inline(always)]
+    /// Returns the offset of the first inlined byte, which is always zero unless the
+    /// `inline-offset` feature is enabled. Only meaningful for inlined (not empty) [`ShortStr`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("hello").slice(1..);
+    /// assert_eq!(string.inline_offset(), if cfg!(feature = "inline-offset") { 1 } else { 0 });
+    /// ```
+    const fn inline_offset(self) -> usize {
+        if cfg!(feature = "inline-offset") {
+            ((self.length_marker() & !INLINE_FLAG) >> OFFSET_SHIFT) as usize
+        } else {
+            0
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the normalized bytes of the [`ShortStr`] as a [`CoveringInt`], which two
+    /// inlined (or empty) [`ShortStr`]s share exactly when their content is equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("hello");
+    /// assert_eq!(string.slice(1..).to_scalar(), ShortStr::from("ello").to_scalar());
+    /// ```
+    const fn to_scalar(self) -> CoveringInt {
+        CoveringInt::from_ne_bytes(self.normalize().to_bytes())
+    }
+
+    #[inline(always)]
+    /// Returns `self` in the exact representation [`ShortStr::from`] produces for its content:
+    /// normalized, and inlined whenever it fits, which unchecked facades might not be. Two
+    /// canonical [`ShortStr`]s with equal content are inlined and share the same bytes, or are
+    /// both facades.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use short_str::ShortStr;
+    ///
+    /// let unchecked = unsafe { ShortStr::from_str_unchecked("hello") };
+    /// assert_eq!(unchecked.canonicalize().to_bytes(), ShortStr::from("hello").to_bytes());
+    /// ```
+    #[cfg(feature = "alloc")]
+    const fn canonicalize(self) -> Self {
+        match self.try_as_original() {
+            Some(original) => ShortStr::from_str(original),
+            None => self.normalize(),
+        }
+    }
+
+    #[inline(always)]
+    /// Returns `self` with the inlined bytes moved to the start and unused bytes zeroed, which is
+    /// the representation [`ShortStr::from`] produces.
+    ///
+    /// Only the `inline-offset` feature (without `eager-normalization`) produces values that
+    /// aren't normalized, through slicing. In every other case this is a no-op. Comparisons and
+    /// hashing normalize on their own, but normalizing a value that is compared often once up
+    /// front saves repeating it.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("hello").slice(1..4);
+    /// assert_eq!(string.normalize(), "ell");
+    /// assert_eq!(string.normalize(), string);
+    /// ```
+    pub const fn normalize(self) -> Self {
+        if LAZY_NORMALIZATION {
+            self.normalize_offset()
+        } else {
+            self
+        }
+    }
+
+    #[inline(always)]
+    /// Returns `self` with the inlined bytes moved from the offset to the start and unused bytes
+    /// zeroed, see [`ShortStr::normalize`], whether or not normalization is lazy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("hello").slice(1..4);
+    /// assert_eq!(string.normalize_offset().to_bytes(), ShortStr::from("ell").to_bytes());
+    /// ```
+    const fn normalize_offset(self) -> Self {
+        match self.variant() {
+            Variant::Inlined(data) => {
+                let offset = self.inline_offset();
+                let len = self.len();
+                // same as slicing without an offset, see ShortStr::slice_range_unchecked
+                let int = CoveringInt::from_ne_bytes(data) & DATA_MASK & prefix_mask(offset + len);
+                let int = shift_to_start(int, offset)
+                    | ((INLINE_FLAG | len as u8) as CoveringInt) << byte_shift(MARKER_INDEX);
+                // safety:
+                // the marker is set and only the inlined bytes are kept
+                unsafe { ShortStr::from_bytes(int.to_ne_bytes()) }
+            }
+            Variant::Facade(_) | Variant::Empty => self,
+        }
     }
 
     #[inline(always)]
@@ -292,11 +718,11 @@ impl<'str_lt> ShortStr<'str_lt> {
     ///
     /// let not_inlined = ShortStr::from("i am too big to fit inside the structure");
     /// assert_eq!(not_inlined.is_str(), true);
-    /// let inlined = ShortStr::from("i am small");
+    /// let inlined = ShortStr::from("small");
     /// assert_eq!(inlined.is_str(), false);
     /// ```
     pub const fn is_str(self) -> bool {
-        self.length_marker() == 0
+        self.length_marker() & FACADE_MASK == 0
     }
 
     #[inline(always)]
@@ -309,28 +735,49 @@ impl<'str_lt> ShortStr<'str_lt> {
     /// ```
     pub const fn len(self) -> usize {
         match self.variant() {
-            Variant::Inlined(data) => data[BYTE_SIZE - 1] as usize,
+            Variant::Inlined(data) => (data[MARKER_INDEX] & LEN_BITS) as usize,
             Variant::Facade(str_ref) => str_ref.len(),
             Variant::Empty => 0,
         }
     }
 
+    #[inline(always)]
+    /// Returns `true` if the [`ShortStr`] has a length of zero bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// assert_eq!(ShortStr::from("").is_empty(), true);
+    /// assert_eq!(ShortStr::from("hi").is_empty(), false);
+    /// ```
+    pub const fn is_empty(self) -> bool {
+        self.len() == 0
+    }
+
     #[inline(always)]
     /// Produce a [`ShortStr`] from a `&str` without checking if it should be Inlined or not. The
     /// function is marked unsafe for the assumptions made on correct convertion relied upon for
     /// other functions.
     ///
-    /// The representation might have differed if [`ShortStr::from`] had been used (e.g. <= 15
-    /// bytes long str, on 64-bit platforms) which would cause the [`Eq`] and [`PartialEq`]
-    /// operations to fail even if they represent the same value.
-    ///
-    /// For example
-    /// ```should_panic
+    /// The representation might differ from the one [`ShortStr::from`] would have produced (e.g.
+    /// <= 15 bytes long str, on 64-bit platforms), which means the fast scalar paths are lost.
+    /// [`Eq`] and [`PartialEq`] still compare the content:
+    /// ```
     /// use short_str::ShortStr;
     ///
-    /// assert_eq!(unsafe { ShortStr::from_str_unchecked("test") }, ShortStr::from("test"));
+    /// let unchecked = unsafe { ShortStr::from_str_unchecked("test") };
+    /// assert_eq!(unchecked.is_str(), true);
+    /// assert_eq!(unchecked, ShortStr::from("test"));
     /// ```
     ///
+    /// # Safety
+    /// The length of `other` must leave the most significant byte of the length untouched (e.g.
+    /// below 2^56 on 64-bit platforms), as that byte is used as the marker for inlined values.
+    /// With the `pointer-storage` feature any `other` is fine.
+    ///
+    /// An empty `other` always produces [`ShortStr::EMPTY`].
+    ///
     /// # Examples
     /// ```
     /// use short_str::ShortStr;
@@ -339,9 +786,13 @@ impl<'str_lt> ShortStr<'str_lt> {
     /// assert_eq!(unsafe { ShortStr::from_str_unchecked(string) }, ShortStr::from(string));
     /// ```
     pub const unsafe fn from_str_unchecked(other: &str) -> Self {
+        // a facade may never have a zero length (see the len field)
+        if other.is_empty() {
+            return Self::EMPTY;
+        }
         // safety:
         // see ShortStr::length_marker(self)
-        // any &str is a valid instance of ShortStr due to the nature of the struct
+        // any non-empty &str is a valid instance of ShortStr due to the nature of the struct
         unsafe { transmute::<&str, ShortStr>(other) }
     }
 
@@ -354,7 +805,7 @@ impl<'str_lt> ShortStr<'str_lt> {
     /// ```
     /// use short_str::ShortStr;
     ///
-    /// let inlined = ShortStr::from("hello, world!");
+    /// let inlined = ShortStr::from("hello!");
     /// assert_eq!(inlined.is_str(), false);
     /// let not_inlined = ShortStr::from("hello, world! i am big");
     /// assert_eq!(not_inlined.is_str(), true);
@@ -377,8 +828,10 @@ impl<'str_lt> ShortStr<'str_lt> {
                 unsafe {
                     copy_nonoverlapping(facade.as_ptr(), data.as_mut_ptr(), facade.len());
                 }
-                data[BYTE_SIZE - 1] = facade.len() as u8;
-                ShortStr { data, _lt: PhantomData }
+                data[MARKER_INDEX] = INLINE_FLAG | facade.len() as u8;
+                // safety:
+                // the marker is set
+                unsafe { ShortStr::from_bytes(data) }
             }
             // It's already a proper ShortStr
             // A: an inlined &str
@@ -389,12 +842,42 @@ impl<'str_lt> ShortStr<'str_lt> {
     }
 
     #[inline(always)]
-    /// View the [`ShortStr`] as a [`&str`]. There are two cases:
-    /// - (not inlined) [`ShortStr`] is a facade for [`&str`]: the original `&str` is returned with
-    ///   its original lifetime.
+    /// Produce a [`ShortStr`] from a [`&str`] of any lifetime, copying it into `arena` only if it
+    /// can't be inlined, so that the [`ShortStr`] lives as long as `arena`.
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::{Arena, ShortStr};
+    ///
+    /// let arena = Arena::new();
+    /// let (inlined, copied) = {
+    ///     let short = String::from("hello");
+    ///     let long = String::from("this string is too long to be inlined");
+    ///     (ShortStr::from_str_in(&short, &arena), ShortStr::from_str_in(&long, &arena))
+    /// };
+    /// assert_eq!(inlined.is_str(), false);
+    /// assert_eq!(copied, "this string is too long to be inlined");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_str_in(value: &str, arena: &'str_lt Arena) -> Self {
+        match ShortStr::from_str(value).try_into_static() {
+            Some(inlined) => inlined,
+            None => ShortStr::from_str(arena.alloc_str(value)),
+        }
+    }
+
+    #[inline(always)]
+    /// View the [`ShortStr`] as a [`&str`] borrowed from `self`. There are two cases:
+    /// - (not inlined) [`ShortStr`] is a facade for [`&str`]: the original `&str` is returned,
+    ///   shortened to the lifetime of the borrow.
     /// - (inlined) [`ShortStr`] is true: a new [`&str`] is constructed to point at `self` with the
     ///   appropriate length.
     ///
+    /// Since an inlined [`ShortStr`] stores its bytes inside of itself the returned [`&str`]
+    /// cannot outlive `self`, see [`ShortStr::try_as_original`] for getting the original
+    /// [`&str`] of a facade.
+    ///
     /// # Examples
     /// These examples assume a little endian 64-bit architecture.
     /// ```
@@ -406,18 +889,132 @@ impl<'str_lt> ShortStr<'str_lt> {
     /// let re_string = short_str.as_str();
     /// assert_eq!(string, short_str);
     /// ```
-    pub const fn as_str(&self) -> &'str_lt str {
+    ///
+    /// The returned [`&str`] is bound to `self`, so it cannot be kept around after `self` is gone:
+    /// ```compile_fail
+    /// use short_str::ShortStr;
+    ///
+    /// let string: &str;
+    /// {
+    ///     let inlined = ShortStr::from("hello");
+    ///     string = inlined.as_str();
+    /// }
+    /// assert_eq!(string, "hello");
+    /// ```
+    pub const fn as_str(&self) -> &str {
         match self.variant() {
-            Variant::Inlined(_) | Variant::Empty => {
+            Variant::Inlined(_) => {
                 // safety:
-                // the ShortStr is an inline str, starting at the same place as data and with length
-                // we get from len
+                // the ShortStr is an inline str, starting at the offset into data and with length
+                // we get from len, the lifetime of the &str is that of &self
                 unsafe {
-                    let slice = core::slice::from_raw_parts(self.data.as_ptr(), self.len());
+                    let data = core::ptr::from_ref(self).cast::<u8>().add(self.inline_offset());
+                    let slice = core::slice::from_raw_parts(data, self.len());
                     core::str::from_utf8_unchecked(slice)
                 }
             }
             Variant::Facade(str_ref) => str_ref,
+            Variant::Empty => "",
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the original [`&str`], with its original lifetime, if the [`ShortStr`] is a
+    /// facade. Inlined (and empty) values hold no reference to the original [`&str`] and produce
+    /// [`None`], use [`ShortStr::as_str`] for those.
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let string = "this is not inlined";
+    /// let original = {
+    ///     let not_inlined = ShortStr::from(string);
+    ///     not_inlined.try_as_original()
+    /// };
+    /// assert_eq!(original, Some(string));
+    ///
+    /// let inlined = ShortStr::from("inlined");
+    /// assert_eq!(inlined.try_as_original(), None);
+    /// ```
+    pub const fn try_as_original(&self) -> Option<&'str_lt str> {
+        match self.variant() {
+            Variant::Facade(str_ref) => Some(str_ref),
+            Variant::Inlined(_) | Variant::Empty => None,
+        }
+    }
+
+    #[inline(always)]
+    /// Compare the representations of two [`ShortStr`] as single scalars: the content of inlined
+    /// values, and the pointer and length of facades. Unlike [`PartialEq`] this never compares
+    /// the bytes of facades, so facades with the same content at different addresses differ.
+    ///
+    /// This is identical to [`PartialEq`] for values of the same `Interner` (`alloc` feature), as
+    /// it only ever stores one copy of each string.
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let string = "this is not inlined";
+    /// let copy = String::from(string);
+    /// assert!(ShortStr::from(string).scalar_eq(ShortStr::from(string)));
+    /// assert!(!ShortStr::from(string).scalar_eq(ShortStr::from(copy.as_str())));
+    /// assert!(ShortStr::from("inlined").scalar_eq(ShortStr::from("inlined")));
+    /// ```
+    pub const fn scalar_eq(self, other: ShortStr<'_>) -> bool {
+        self.to_scalar() == other.to_scalar()
+    }
+
+    #[inline(always)]
+    /// Returns `self` with a `'static` lifetime if it's inlined (or empty), as it doesn't borrow
+    /// anything in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use short_str::ShortStr;
+    ///
+    /// let string = String::from("hello");
+    /// let inlined: ShortStr<'static> = ShortStr::from(string.as_str()).try_into_static().unwrap();
+    /// ```
+    const fn try_into_static(self) -> Option<ShortStr<'static>> {
+        if self.is_str() {
+            None
+        } else {
+            // safety:
+            // inlined values hold their own bytes and are not tied to any lifetime
+            Some(unsafe { transmute::<ShortStr<'str_lt>, ShortStr<'static>>(self) })
+        }
+    }
+
+    #[inline(always)]
+    /// Produce a [`ShortStr`] facade from a pointer and a length without going through a
+    /// reference, keeping the provenance of `ptr` as is (e.g. for deallocating through it later).
+    ///
+    /// # Safety
+    /// `ptr` and `len` must make up a valid [`&str`] living for `'str_lt`, with `len` larger than
+    /// [`INLINE_BYTE_SIZE`] and leaving the marker unset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use short_str::ShortStr;
+    ///
+    /// let string = "this string is way too long to be inlined";
+    /// let short = unsafe { ShortStr::from_raw_parts(string.as_ptr(), string.len()) };
+    /// assert_eq!(short, string);
+    /// ```
+    #[cfg(feature = "alloc")]
+    const unsafe fn from_raw_parts(ptr: *const u8, len: usize) -> Self {
+        ShortStr {
+            ptr,
+            // safety:
+            // len > INLINE_BYTE_SIZE as garantueed by the caller
+            len: unsafe { NonZeroUsize::new_unchecked(len) },
+            _lt: PhantomData,
         }
     }
 
@@ -439,6 +1036,11 @@ impl<'str_lt> ShortStr<'str_lt> {
     /// To be clear; splitting graphemes inside the inlined [`&str`] does currently not produce a
     /// panic.
     ///
+    /// # Safety
+    /// `slice` must be in ascending order, within `0..=self.len()`, and must not split graphemes.
+    /// Breaking this for an inlined [`ShortStr`] produces a value with invalid UTF-8 or garbage
+    /// bytes.
+    ///
     /// # Examples
     /// These examples assume a little endian 64-bit architecture.
     /// ```
@@ -449,8 +1051,7 @@ impl<'str_lt> ShortStr<'str_lt> {
     /// assert_eq!(slice, "hello");
     /// ```
     ///
-    /// Attempting to use an incorrect slice on a [`&str`] facade results in a panic from the
-    /// [`&str`]:
+    /// Attempting to use an incorrect slice on a [`&str`] facade still results in a panic:
     /// ```should_panic
     /// use short_str::ShortStr;
     ///
@@ -470,11 +1071,11 @@ impl<'str_lt> ShortStr<'str_lt> {
     /// ```
     /// use short_str::ShortStr;
     ///
-    /// let inlined = ShortStr::from("hello, world!");
-    /// assert_eq!(inlined.len(), 13);
+    /// let inlined = ShortStr::from("hello");
+    /// assert_eq!(inlined.len(), 5);
     /// let slice   = unsafe { inlined.slice_unchecked(..inlined.len() + 1) };
-    /// assert_eq!(slice.len(), 14);
-    /// assert_eq!(slice, "hello, world!\0");
+    /// assert_eq!(slice.len(), 6);
+    /// assert_eq!(slice, "hello\0");
     /// ```
     /// Neither does it panic on splitting graphemes, though this will panic since its invalid
     /// UTF-8 (the equality also fails but you get the point):
@@ -486,19 +1087,73 @@ impl<'str_lt> ShortStr<'str_lt> {
     /// assert_eq!(slice, "unicode �");
     /// ```
     pub unsafe fn slice_unchecked(self, slice: impl RangeBounds<usize>) -> Self {
-        let range = self.bounds_to_range(slice);
+        // safety:
+        // bounds within 0..=self.len() can't overflow when realized
+        let range = unsafe { self.bounds_to_range(slice).unwrap_unchecked() };
+        // safety:
+        // upheld by the caller
+        unsafe { self.slice_range_unchecked(range.start, range.end) }
+    }
 
+    #[inline(always)]
+    /// Performs the slicing operation `start..end` on `self` without checking for logical
+    /// consistencies, like [`ShortStr::slice_unchecked`] but usable in const contexts. See
+    /// [`ShortStr::slice_range`] for the checked variant.
+    ///
+    /// # Panics
+    /// Only when `self` is a [`&str`] facade and `start..end` is out of bounds or splits
+    /// graphemes, as the result would otherwise point outside or into the middle of the original
+    /// [`&str`].
+    ///
+    /// # Safety
+    /// See [`ShortStr::slice_unchecked`].
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// const STRING: ShortStr = ShortStr::from_str("hello, world!");
+    /// const SLICE: ShortStr = unsafe { STRING.slice_range_unchecked(7, 12) };
+    /// assert_eq!(SLICE, "world");
+    /// ```
+    pub const unsafe fn slice_range_unchecked(self, start: usize, end: usize) -> Self {
         // assumptions:
-        // `slice` is correctly ordered (no end < start) and sized (no end > self.len())
+        // `start..end` is correctly ordered (no end < start) and sized (no end > self.len())
+        let len = end.wrapping_sub(start);
         match self.variant() {
             // include these if statements here just cause its prettier :p
             // if the slice is zero length then its just the empty case
-            _ if range.len() == 0 => Self::EMPTY,
+            _ if start >= end => Self::EMPTY,
             // if they are the same length then its a nop
-            _ if self.len() == range.len() => self,
+            _ if self.len() == len => self,
             // &str facades should be handled by &str, then handle &str as ShortStr in case its
             // short enough to inline
-            Variant::Facade(str_ref) => Self::from_str(&str_ref[range]),
+            Variant::Facade(str_ref) => {
+                if !self.is_char_boundary(start) || !self.is_char_boundary(end) {
+                    panic!("slicing a &str facade out of bounds or inside of a grapheme");
+                }
+                // &str_ref[start..end] without the non-const Index
+                let (bytes, _) = str_ref.as_bytes().split_at(end);
+                let (_, bytes) = bytes.split_at(start);
+                // safety:
+                // both ends are char boundaries of str_ref as checked above
+                Self::from_str(unsafe { core::str::from_utf8_unchecked(bytes) })
+            }
+            // move the window over the inlined data, leaving the bytes as they are until they're
+            // normalized, right away with eager-normalization or lazily otherwise
+            Variant::Inlined(mut data) if cfg!(feature = "inline-offset") => {
+                let offset = self.inline_offset() + start;
+                data[MARKER_INDEX] = INLINE_FLAG | (offset << OFFSET_SHIFT) as u8 | len as u8;
+                // safety:
+                // the marker is set, with a window inside of the previous one
+                let sliced = unsafe { ShortStr::from_bytes(data) };
+                if LAZY_NORMALIZATION {
+                    sliced
+                } else {
+                    sliced.normalize_offset()
+                }
+            }
             // bit manipulate the inlined data
             Variant::Inlined(data) => {
                 // if its a ShortStr we manipulate the bytes to the correct state
@@ -514,42 +1169,34 @@ impl<'str_lt> ShortStr<'str_lt> {
                 //     int   = 0x03_EF_CD_AB
                 let int = CoveringInt::from_ne_bytes(data);
                 // get new length
-                let len = range.len() as i8;
+                let len = len as u8;
                 let int = if len == 0 {
-                    // Ex: len = 0x80
-                    // set to -1 if data is zero length
-                    let len = -1i8;
-                    // don't bother using the actual data
-                    // Ex: len = 0x00_00_00_80 (cast)
-                    //     len = 0x80_00_00_00 (rotate)
-                    //     int = len
-                    (len as CoveringInt).rotate_right(8)
+                    // only the marker set to -1 (0xFF) if data is zero length, same as EMPTY
+                    // Ex: int = 0xFF_00_00_00
+                    SIZE_MASK
                 } else {
                     // remove the length
                     // Ex: int  = 0x03_EF_CD_AB
                     //     mask = 0x00_FF_FF_FF
                     //     data = 0x00_EF_CD_AB
                     let data = int & DATA_MASK;
-                    // mask the bytes to the left of slice.end (or right in integer representation)
-                    // Ex: upper = 0x00_FF_FF_FF (mask)
-                    //     upper = 0xFF_00_00_00 (lsh end = 3 bytes)
-                    //     upper = 0x00_FF_FF_FF (invert)
-                    let upper_data_mask = !(DATA_MASK /* or CoveringInt::MAX */ << range.end * 8);
-                    // Ex: data = 0x00_EF_CD_AB
-                    //     data = 0x00_00_CD_AB (mask)
-                    let data = data & upper_data_mask;
+                    // mask the bytes from slice.end and on (left in little endian integer
+                    // representation, right in big endian)
+                    // Ex: upper = 0x00_FF_FF_FF (prefix_mask(end = 3))
+                    //     data  = 0x00_EF_CD_AB (mask)
+                    let data = data & prefix_mask(end);
                     // move over data between slice.start and slice.end to be at the start of data
-                    // Ex: data = 0x00_00_CD_AB
-                    //     data = 0x00_00_00_CD (rsh start = 1 bytes)
-                    let data = data >> range.start * 8;
+                    // Ex: data = 0x00_EF_CD_AB
+                    //     data = 0x00_00_EF_CD (rsh start = 1 bytes, lsh on big endian)
+                    let data = shift_to_start(data, start);
 
                     // meld back together
-                    // Ex: data = 0x00_00_00_AB
-                    //     len  = 0x01
-                    //     len  = 0x00_00_00_01 (cast)
-                    //     len  = 0x01_00_00_00 (rotate)
-                    //     int  = 0x01_00_00_AB
-                    data | (len as CoveringInt).rotate_right(8)
+                    // Ex: data = 0x00_00_EF_CD
+                    //     len  = 0x02
+                    //     len  = 0x00_00_00_02 (cast)
+                    //     len  = 0x02_00_00_00 (shift to marker)
+                    //     int  = 0x02_00_EF_CD
+                    data | ((INLINE_FLAG | len) as CoveringInt) << byte_shift(MARKER_INDEX)
                 };
                 // turn back into correct data type
                 // safety:
@@ -558,40 +1205,43 @@ impl<'str_lt> ShortStr<'str_lt> {
                 //
                 unsafe { transmute::<CoveringInt, ShortStr>(int) }
             }
-            _ => unreachable!(),
+            Variant::Empty => unreachable!(),
         }
     }
 
     /// Converts the range `bounds` to an actual range based on `self` as a context.
-    /// The RangeBounds trait is implemented for all range operators (`..`, `..N`, etc) but any
-    /// other value that does support the trait is also welcome.
+    /// The RangeBounds trait is implemented for all range operators (`..`, `..N`, etc) and
+    /// `(Bound, Bound)` tuples, but any other value that does support the trait is also welcome.
     ///
-    /// Does not perform any checks on the resulting range.
+    /// Does not perform any checks on the resulting range, other than producing
+    /// [`SliceError::Overflow`] if an excluded start or included end is `usize::MAX`.
     ///
     /// # Examples
     /// These examples assume a little endian 64-bit architecture.
     /// ```rust,ignore
+    /// use core::ops::Bound;
     /// use short_str::ShortStr;
     ///
     /// let string = ShortStr::from("hello, world");
-    /// let range  = string.bounds_to_range(..);
-    /// assert_eq!(range, 0..string.len());
+    /// assert_eq!(string.bounds_to_range(..), Ok(0..string.len()));
+    /// assert_eq!(string.bounds_to_range((Bound::Excluded(1), Bound::Included(3))), Ok(2..4));
+    /// assert_eq!(string.bounds_to_range(..=usize::MAX), Err(SliceError::Overflow));
     /// ```
-    fn bounds_to_range(self, bounds: impl RangeBounds<usize>) -> Range<usize> {
+    fn bounds_to_range(self, bounds: impl RangeBounds<usize>) -> Result<Range<usize>, SliceError> {
         // If this isn't optimized away by monomorphism I'm going to shoot myself and the compiler
         let realized_start = match bounds.start_bound() {
-            core::ops::Bound::Included(&x) => x,
-            core::ops::Bound::Unbounded => 0,
-            _ => unreachable!(),
+            Bound::Included(&x) => x,
+            Bound::Excluded(&x) => x.checked_add(1).ok_or(SliceError::Overflow)?,
+            Bound::Unbounded => 0,
         };
 
         let realized_end_exclusive = match bounds.end_bound() {
-            core::ops::Bound::Included(&x) => x + 1,
-            core::ops::Bound::Excluded(&x) => x,
-            core::ops::Bound::Unbounded => self.len(),
+            Bound::Included(&x) => x.checked_add(1).ok_or(SliceError::Overflow)?,
+            Bound::Excluded(&x) => x,
+            Bound::Unbounded => self.len(),
         };
 
-        realized_start..realized_end_exclusive
+        Ok(realized_start..realized_end_exclusive)
     }
 
     /// Performs the slicing operation using `slice` on `self`, ensuring logical consistencies
@@ -603,13 +1253,9 @@ impl<'str_lt> ShortStr<'str_lt> {
     /// - `!self.is_str()` (inlined [`&str`]): bitbash the bytes into the correct sliced version.
     ///
     /// # Panics
-    /// If `slice` is illogical (descending order or out of bounds values) the function panics.
-    /// Otherwise, if `slice` splits graphemes, the operation only panics for the case of when its
-    /// representing an actual [`&str`] (i.e. not inlined). See [`&str`] for more details
-    /// (particularly on splitting graphemes).
-    ///
-    /// To be clear; splitting graphemes inside the inlined [`&str`] does currently not produce a
-    /// panic.
+    /// If `slice` is illogical (descending order or out of bounds values) or splits graphemes
+    /// the function panics, exactly like slicing a [`&str`] does, whether inlined or not. See
+    /// [`ShortStr::try_slice`] for the non-panicking variant.
     ///
     /// # Examples
     /// These examples assume a little endian 64-bit architecture.
@@ -628,24 +1274,682 @@ impl<'str_lt> ShortStr<'str_lt> {
     /// let not_inlined = ShortStr::from("hello, world! yet another inlined string");
     /// let slice       = not_inlined.slice(..not_inlined.len() + 1);
     /// ```
+    /// as does splitting a grapheme of an inlined [`ShortStr`]:
+    /// ```should_panic
+    /// use short_str::ShortStr;
+    ///
+    /// let inlined = ShortStr::from("unicode 🈁s");
+    /// let slice   = inlined.slice(..inlined.len() - 2);
+    /// ```
     pub fn slice(self, slice: impl RangeBounds<usize>) -> Self {
-        let range = self.bounds_to_range(slice);
-
-        assert!(
-            range.start <= range.end,
-            "expected slice on ShortStr to have {{start}} <= {{end}}"
-        );
-
-        assert!(
-            range.end <= self.len(),
-            "expected slice on ShortStr to have {{end}} < {{length}}"
-        );
-
+        let bounds = (slice.start_bound().cloned(), slice.end_bound().cloned());
+        match self.try_slice(bounds) {
+            Ok(slice) => slice,
+            Err(_) => {
+                // let &str produce the panic to get the exact same message, which for overflowing
+                // bounds can only be done through the bounds themselves
+                match self.bounds_to_range(bounds) {
+                    Ok(range) => _ = &self.as_str()[range],
+                    Err(_) => _ = &self.as_str()[bounds],
+                }
+                unreachable!("expected slicing &str to panic where slicing ShortStr failed")
+            }
+        }
+    }
+
+    /// Performs the slicing operation using `slice` on `self` like [`ShortStr::slice`], but
+    /// produces a [`SliceError`] instead of panicking.
+    ///
+    /// # Errors
+    /// - [`SliceError::OutOfBounds`]: `slice` reaches past the end of `self`
+    /// - [`SliceError::Descending`]: `slice` is in descending order
+    /// - [`SliceError::NotCharBoundary`]: `slice` splits a grapheme
+    /// - [`SliceError::Overflow`]: an excluded start or included end of `slice` is `usize::MAX`
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::{ShortStr, SliceError};
+    ///
+    /// let string = ShortStr::from("unicode 🈁s");
+    /// assert_eq!(string.try_slice(..7), Ok(ShortStr::from("unicode")));
+    /// assert_eq!(string.try_slice(..9), Err(SliceError::NotCharBoundary { index: 9 }));
+    /// assert_eq!(string.try_slice(..20), Err(SliceError::OutOfBounds { index: 20, len: 13 }));
+    /// ```
+    pub fn try_slice(self, slice: impl RangeBounds<usize>) -> Result<Self, SliceError> {
+        let range = self.bounds_to_range(slice)?;
+        self.try_slice_range(range.start, range.end)
+    }
+
+    /// Performs the slicing operation `start..end` on `self` like [`ShortStr::try_slice`], but
+    /// usable in const contexts.
+    ///
+    /// # Errors
+    /// See [`ShortStr::try_slice`].
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::{ShortStr, SliceError};
+    ///
+    /// const STRING: ShortStr = ShortStr::from_str("unicode 🈁s");
+    /// const SLICE: Result<ShortStr, SliceError> = STRING.try_slice_range(0, 9);
+    /// assert_eq!(SLICE, Err(SliceError::NotCharBoundary { index: 9 }));
+    /// ```
+    pub const fn try_slice_range(self, start: usize, end: usize) -> Result<Self, SliceError> {
+        let len = self.len();
+
+        if start > len || end > len {
+            let index = if start > len { start } else { end };
+            return Err(SliceError::OutOfBounds { index, len });
+        }
+
+        if start > end {
+            return Err(SliceError::Descending { start, end });
+        }
+
+        if !self.is_char_boundary(start) {
+            return Err(SliceError::NotCharBoundary { index: start });
+        }
+
+        if !self.is_char_boundary(end) {
+            return Err(SliceError::NotCharBoundary { index: end });
+        }
+
         // safety:
         // slice bounds have been verified to be correct above
+        Ok(unsafe { self.slice_range_unchecked(start, end) })
+    }
+
+    /// Performs the slicing operation `start..end` on `self` like [`ShortStr::slice`], but usable
+    /// in const contexts, e.g. for computing tables of prefixes at compile-time.
+    ///
+    /// # Panics
+    /// If `start..end` is illogical (descending order or out of bounds values) or splits
+    /// graphemes, with the message of the corresponding [`SliceError`]. In const contexts this
+    /// fails compilation instead.
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// const KEYWORD: ShortStr = ShortStr::from_str("function");
+    /// const PREFIXES: [ShortStr; 3] = [
+    ///     KEYWORD.slice_range(0, 2),
+    ///     KEYWORD.slice_range(0, 3),
+    ///     KEYWORD.slice_range(0, 4),
+    /// ];
+    /// assert_eq!(PREFIXES, ["fu", "fun", "func"]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use short_str::ShortStr;
+    ///
+    /// const SLICE: ShortStr = ShortStr::from_str("unicode 🈁s").slice_range(0, 9);
+    /// ```
+    pub const fn slice_range(self, start: usize, end: usize) -> Self {
+        match self.try_slice_range(start, end) {
+            Ok(slice) => slice,
+            Err(error) => error.panic(),
+        }
+    }
+
+    #[inline(always)]
+    /// Checks that `index` is the first byte of a grapheme, or the end, of `self`. Mirrors
+    /// [`str::is_char_boundary`] but reads the inlined bytes directly.
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("unicode 🈁s");
+    /// assert_eq!(string.is_char_boundary(8), true);
+    /// assert_eq!(string.is_char_boundary(9), false);
+    /// assert_eq!(string.is_char_boundary(13), true);
+    /// assert_eq!(string.is_char_boundary(14), false);
+    /// ```
+    pub const fn is_char_boundary(self, index: usize) -> bool {
+        if index == 0 {
+            return true;
+        }
+
+        let len = self.len();
+        if index >= len {
+            return index == len;
+        }
+
+        let byte = match self.variant() {
+            Variant::Inlined(data) => data[self.inline_offset() + index],
+            Variant::Facade(str_ref) => str_ref.as_bytes()[index],
+            // len is zero, so handled above
+            Variant::Empty => return false,
+        };
+
+        // continuation bytes are 0b10xx_xxxx, which are the only ones below -0x40 as i8
+        (byte as i8) >= -0x40
+    }
+
+    /// Returns a sub-[`ShortStr`] of `self`, mirroring [`str::get`]. [`None`] is produced
+    /// whenever indexing a [`&str`] with `range` would panic, i.e. when `range` is out of bounds,
+    /// in descending order, or splits graphemes.
+    ///
+    /// Unlike [`str::get`] the result is a [`ShortStr`], which keeps the lifetime of the original
+    /// [`&str`] for facades.
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("unicode 🈁s");
+    /// assert_eq!(string.get(..7), Some(ShortStr::from("unicode")));
+    /// assert_eq!(string.get(..9), None);
+    /// assert_eq!(string.get(..20), None);
+    /// ```
+    pub fn get(self, range: impl RangeBounds<usize>) -> Option<Self> {
+        self.try_slice(range).ok()
+    }
+
+    /// Returns a sub-[`ShortStr`] of `self` without any checks, mirroring [`str::get_unchecked`].
+    /// See [`ShortStr::get`] for the checked variant.
+    ///
+    /// # Safety
+    /// See [`ShortStr::slice_unchecked`].
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("hello, world!");
+    /// assert_eq!(unsafe { string.get_unchecked(7..) }, "world!");
+    /// ```
+    pub unsafe fn get_unchecked(self, range: impl RangeBounds<usize>) -> Self {
+        // safety:
+        // upheld by the caller
         unsafe { self.slice_unchecked(range) }
     }
+
+    #[inline(always)]
+    /// Returns the inlined bytes of `self` in a [`CoveringInt`] made through
+    /// [`CoveringInt::from_ne_bytes`], without the marker. Facades are inlined first, so this is
+    /// only meaningful when `self.len() <= INLINE_BYTE_SIZE`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("ab");
+    /// assert_eq!(string.inline_data(), u128::from_le_bytes(*b"ab\0\0\0\0\0\0\0\0\0\0\0\0\0\0"));
+    /// ```
+    const fn inline_data(self) -> CoveringInt {
+        let data = match self.variant() {
+            Variant::Inlined(_) => self.normalize().to_bytes(),
+            // facades of short content through from_str_unchecked
+            Variant::Facade(str_ref) => ShortStr::from_str(str_ref).to_bytes(),
+            Variant::Empty => return 0,
+        };
+        CoveringInt::from_ne_bytes(data) & DATA_MASK
+    }
+
+    /// Concatenates `self` and `other` into an inlined [`ShortStr`], which succeeds whenever the
+    /// combined length fits inline. The bytes of `other` are shifted in right after the bytes of
+    /// `self`, so no buffer is needed.
+    ///
+    /// See `ShortStr::concat` for a fallback to an owned string (`alloc` feature).
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// const NAMESPACE: ShortStr = ShortStr::from_str("std::");
+    /// const PATH: Option<ShortStr> = NAMESPACE.try_concat(ShortStr::from_str("fmt"));
+    /// assert_eq!(PATH, Some(ShortStr::from("std::fmt")));
+    ///
+    /// let long = ShortStr::from("a string that is too long");
+    /// assert_eq!(NAMESPACE.try_concat(long), None);
+    /// ```
+    pub const fn try_concat(self, other: ShortStr<'_>) -> Option<ShortStr<'static>> {
+        let len = self.len() + other.len();
+        if len > INLINE_BYTE_SIZE {
+            return None;
+        }
+        if len == 0 {
+            return Some(ShortStr::EMPTY);
+        }
+
+        // Ex: self  = 0x02_00_CD_AB
+        //     other = 0x01_00_00_EF
+        //     int   = 0x00_00_CD_AB | 0x00_EF_00_00 (lsh self.len() = 2 bytes, rsh on big endian)
+        //     int   = 0x03_EF_CD_AB (marker)
+        let int = self.inline_data()
+            | shift_from_start(other.inline_data(), self.len())
+            | ((INLINE_FLAG | len as u8) as CoveringInt) << byte_shift(MARKER_INDEX);
+        // safety:
+        // the marker is set and the data only contains the bytes of both &str, which are each
+        // valid UTF-8
+        Some(unsafe { ShortStr::from_bytes(int.to_ne_bytes()) })
+    }
+
+    /// Concatenates `self` and `other` like [`ShortStr::try_concat`], falling back to allocating
+    /// a [`ShortString`] when the combined length doesn't fit inline.
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let namespace = ShortStr::from("std::");
+    /// let path = namespace.concat(ShortStr::from("collections::HashMap"));
+    /// assert_eq!(path.is_heap(), true);
+    /// assert_eq!(path, "std::collections::HashMap");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn concat(self, other: ShortStr<'_>) -> ShortString {
+        match self.try_concat(other) {
+            Some(concat) => ShortString::from(concat),
+            None => {
+                let mut string = alloc::string::String::with_capacity(self.len() + other.len());
+                string.push_str(self.as_str());
+                string.push_str(other.as_str());
+                ShortString::from(string)
+            }
+        }
+    }
+
+    #[inline(always)]
+    /// Returns an iterator over the pieces of `self` separated by `pattern`, mirroring
+    /// [`str::split`].
+    ///
+    /// Unlike [`str::split`] the pieces are [`ShortStr`]s with the lifetime of the original
+    /// [`&str`], rather than borrowing from `self`. Pieces of a facade stay facades of the
+    /// original [`&str`] unless they are short enough to be inlined.
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let string = "a,b,,c";
+    /// let pieces: Vec<ShortStr> = {
+    ///     let temporary = ShortStr::from(string);
+    ///     temporary.split(',').collect()
+    /// };
+    /// assert_eq!(pieces, ["a", "b", "", "c"]);
+    ///
+    /// let string = "namespace::with::a::very::long::name";
+    /// let pieces: Vec<ShortStr> = ShortStr::from(string).split("::").collect();
+    /// assert_eq!(pieces, ["namespace", "with", "a", "very", "long", "name"]);
+    /// ```
+    pub fn split<P: ShortPattern>(self, pattern: P) -> Split<'str_lt, P> {
+        Split::new(self, pattern)
+    }
+
+    /// Splits `self` around the first match of `pattern`, mirroring [`str::split_once`] but
+    /// producing [`ShortStr`]s with the lifetime of the original [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("key=value=1");
+    /// let (key, value) = string.split_once('=').unwrap();
+    /// assert_eq!((key, value), (ShortStr::from("key"), ShortStr::from("value=1")));
+    /// assert_eq!(string.split_once(':'), None);
+    /// ```
+    pub fn split_once<P: ShortPattern>(self, mut pattern: P) -> Option<(Self, Self)> {
+        let (start, end) = pattern.find_in(self.as_str())?;
+        // safety:
+        // the match is within self and at char boundaries
+        unsafe {
+            Some((
+                self.slice_range_unchecked(0, start),
+                self.slice_range_unchecked(end, self.len()),
+            ))
+        }
+    }
+
+    /// Splits `self` around the last match of `pattern`, mirroring [`str::rsplit_once`] but
+    /// producing [`ShortStr`]s with the lifetime of the original [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("key=value=1");
+    /// let (key, value) = string.rsplit_once('=').unwrap();
+    /// assert_eq!((key, value), (ShortStr::from("key=value"), ShortStr::from("1")));
+    /// assert_eq!(string.rsplit_once(':'), None);
+    /// ```
+    pub fn rsplit_once<P: ShortPattern>(self, mut pattern: P) -> Option<(Self, Self)> {
+        let (start, end) = pattern.rfind_in(self.as_str())?;
+        // safety:
+        // the match is within self and at char boundaries
+        unsafe {
+            Some((
+                self.slice_range_unchecked(0, start),
+                self.slice_range_unchecked(end, self.len()),
+            ))
+        }
+    }
+
+    #[inline(always)]
+    /// Returns an iterator over the lines of `self`, mirroring [`str::lines`] but producing
+    /// [`ShortStr`]s with the lifetime of the original [`&str`]. Lines end with either `\n` or
+    /// `\r\n`, and a final empty line is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("a\r\nb\n\nc\n");
+    /// let lines: Vec<ShortStr> = string.lines().collect();
+    /// assert_eq!(lines, ["a", "b", "", "c"]);
+    /// ```
+    pub fn lines(self) -> Lines<'str_lt> {
+        Lines::new(self)
+    }
+
+    #[inline(always)]
+    /// Returns an iterator over the pieces of `self` separated by any amount of whitespace,
+    /// mirroring [`str::split_whitespace`] but producing [`ShortStr`]s with the lifetime of the
+    /// original [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from(" fn  main()\t{ }\n");
+    /// let words: Vec<ShortStr> = string.split_whitespace().collect();
+    /// assert_eq!(words, ["fn", "main()", "{", "}"]);
+    /// ```
+    pub fn split_whitespace(self) -> SplitWhitespace<'str_lt> {
+        SplitWhitespace::new(self)
+    }
+
+    #[inline(always)]
+    /// Returns `self` without leading and trailing whitespace, mirroring [`str::trim`] but
+    /// producing a [`ShortStr`] with the lifetime of the original [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let trimmed = {
+    ///     let temporary = ShortStr::from(" \thello\n");
+    ///     temporary.trim()
+    /// };
+    /// assert_eq!(trimmed, "hello");
+    /// ```
+    pub fn trim(self) -> Self {
+        self.trim_matches(char::is_whitespace)
+    }
+
+    #[inline(always)]
+    /// Returns `self` without leading whitespace, mirroring [`str::trim_start`] but producing a
+    /// [`ShortStr`] with the lifetime of the original [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// assert_eq!(ShortStr::from(" \thello\n").trim_start(), "hello\n");
+    /// ```
+    pub fn trim_start(self) -> Self {
+        self.trim_start_matches(char::is_whitespace)
+    }
+
+    #[inline(always)]
+    /// Returns `self` without trailing whitespace, mirroring [`str::trim_end`] but producing a
+    /// [`ShortStr`] with the lifetime of the original [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// assert_eq!(ShortStr::from(" \thello\n").trim_end(), " \thello");
+    /// ```
+    pub fn trim_end(self) -> Self {
+        self.trim_end_matches(char::is_whitespace)
+    }
+
+    /// Returns `self` without any repeated leading and trailing matches of `pattern`, mirroring
+    /// [`str::trim_matches`] but producing a [`ShortStr`] with the lifetime of the original
+    /// [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// assert_eq!(ShortStr::from("--key--").trim_matches('-'), "key");
+    /// assert_eq!(ShortStr::from("1k2y3").trim_matches(char::is_numeric), "k2y");
+    /// ```
+    pub fn trim_matches<P: ShortPattern>(self, mut pattern: P) -> Self {
+        let string = self.as_str();
+        let mut start = 0;
+        while let Some(len @ 1..) = pattern.prefix_len(&string[start..]) {
+            start += len;
+        }
+        let mut end = string.len();
+        while let Some(len @ 1..) = pattern.suffix_len(&string[start..end]) {
+            end -= len;
+        }
+        // safety:
+        // start and end are at the ends of matches, which are char boundaries within self
+        unsafe { self.slice_range_unchecked(start, end) }
+    }
+
+    /// Returns `self` without any repeated leading matches of `pattern`, mirroring
+    /// [`str::trim_start_matches`] but producing a [`ShortStr`] with the lifetime of the original
+    /// [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// assert_eq!(ShortStr::from("ababkey").trim_start_matches("ab"), "key");
+    /// ```
+    pub fn trim_start_matches<P: ShortPattern>(self, mut pattern: P) -> Self {
+        let string = self.as_str();
+        let mut start = 0;
+        while let Some(len @ 1..) = pattern.prefix_len(&string[start..]) {
+            start += len;
+        }
+        // safety:
+        // start is at the end of a match, which is a char boundary within self
+        unsafe { self.slice_range_unchecked(start, string.len()) }
+    }
+
+    /// Returns `self` without any repeated trailing matches of `pattern`, mirroring
+    /// [`str::trim_end_matches`] but producing a [`ShortStr`] with the lifetime of the original
+    /// [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// assert_eq!(ShortStr::from("key;;").trim_end_matches(';'), "key");
+    /// ```
+    pub fn trim_end_matches<P: ShortPattern>(self, mut pattern: P) -> Self {
+        let string = self.as_str();
+        let mut end = string.len();
+        while let Some(len @ 1..) = pattern.suffix_len(&string[..end]) {
+            end -= len;
+        }
+        // safety:
+        // end is at the start of a match, which is a char boundary within self
+        unsafe { self.slice_range_unchecked(0, end) }
+    }
+
+    /// Returns `self` without `prefix` if it starts with it, mirroring [`str::strip_prefix`] but
+    /// producing a [`ShortStr`] with the lifetime of the original [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("--verbose");
+    /// assert_eq!(string.strip_prefix("--"), Some(ShortStr::from("verbose")));
+    /// assert_eq!(string.strip_prefix('v'), None);
+    /// ```
+    pub fn strip_prefix<P: ShortPattern>(self, mut prefix: P) -> Option<Self> {
+        let len = prefix.prefix_len(self.as_str())?;
+        // safety:
+        // len is the length of a match at the start, which ends at a char boundary within self
+        Some(unsafe { self.slice_range_unchecked(len, self.len()) })
+    }
+
+    /// Returns `self` without `suffix` if it ends with it, mirroring [`str::strip_suffix`] but
+    /// producing a [`ShortStr`] with the lifetime of the original [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStr;
+    ///
+    /// let string = ShortStr::from("main.rs");
+    /// assert_eq!(string.strip_suffix(".rs"), Some(ShortStr::from("main")));
+    /// assert_eq!(string.strip_suffix('m'), None);
+    /// ```
+    pub fn strip_suffix<P: ShortPattern>(self, mut suffix: P) -> Option<Self> {
+        let len = suffix.suffix_len(self.as_str())?;
+        // safety:
+        // len is the length of a match at the end, which starts at a char boundary within self
+        Some(unsafe { self.slice_range_unchecked(0, self.len() - len) })
+    }
+}
+
+/// Implements [`Index`] for every range type, with the exact same semantics as indexing a [`str`]
+macro_rules! impl_index {
+    ($($range:ty),+ $(,)?) => {
+        $(
+            impl Index<$range> for ShortStr<'_> {
+                type Output = str;
+
+                #[inline(always)]
+                fn index(&self, index: $range) -> &Self::Output {
+                    &self.as_str()[index]
+                }
+            }
+        )+
+    };
+}
+
+impl_index!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeToInclusive<usize>,
+    (Bound<usize>, Bound<usize>),
+);
+
+/// Implements the [`str`]-like traits of a type wrapping a [`ShortStr`] in a `repr` field, with
+/// an `EMPTY` constant and an `as_str` method, by reusing the implementations of [`ShortStr`]
+macro_rules! impl_str_like {
+    ($type:ty) => {
+        impl ::core::default::Default for $type {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::EMPTY
+            }
+        }
+
+        impl ::core::fmt::Debug for $type {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "{:?}", self.as_str())
+            }
+        }
+
+        impl ::core::fmt::Display for $type {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+
+        impl ::core::ops::Deref for $type {
+            type Target = str;
+
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                self.as_str()
+            }
+        }
+
+        impl ::core::borrow::Borrow<str> for $type {
+            #[inline(always)]
+            fn borrow(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl ::core::cmp::PartialEq<$type> for $type {
+            #[inline(always)]
+            fn eq(&self, other: &$type) -> bool {
+                // reuse PartialEq<ShortStr> for ShortStr
+                self.repr == other.repr
+            }
+        }
+
+        impl ::core::cmp::Eq for $type {}
+
+        impl ::core::cmp::PartialEq<$crate::ShortStr<'_>> for $type {
+            #[inline(always)]
+            fn eq(&self, other: &$crate::ShortStr) -> bool {
+                self.repr == *other
+            }
+        }
+
+        impl ::core::cmp::PartialEq<$type> for $crate::ShortStr<'_> {
+            #[inline(always)]
+            fn eq(&self, other: &$type) -> bool {
+                *self == other.repr
+            }
+        }
+
+        impl ::core::cmp::PartialEq<&str> for $type {
+            #[inline(always)]
+            fn eq(&self, other: &&str) -> bool {
+                self.repr == *other
+            }
+        }
+
+        impl ::core::cmp::PartialEq<$type> for &str {
+            #[inline(always)]
+            fn eq(&self, other: &$type) -> bool {
+                other.eq(self)
+            }
+        }
+
+        impl ::core::cmp::Ord for $type {
+            #[inline(always)]
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                // reuse Ord for ShortStr
+                self.repr.cmp(&other.repr)
+            }
+        }
+
+        impl ::core::cmp::PartialOrd for $type {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl ::core::hash::Hash for $type {
+            #[inline(always)]
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                // has to be identical to str for Borrow<str>, as is the one of ShortStr
+                self.repr.hash(state)
+            }
+        }
+    };
 }
+use impl_str_like;
 
 impl<'str_lt> From<&'str_lt str> for ShortStr<'str_lt> {
     #[inline(always)]
@@ -666,10 +1970,16 @@ impl Deref for ShortStr<'_> {
 impl PartialEq<ShortStr<'_>> for ShortStr<'_> {
     #[inline(always)]
     fn eq(&self, other: &ShortStr) -> bool {
-        // by using an int type that covers all bytes the compiler can determine what
-        // the optimal bit-size to use on instruction level (best case its actually e.g. 128-bit
-        // cmp instruction)
-        CoveringInt::from_ne_bytes(self.data) == CoveringInt::from_ne_bytes(other.data)
+        if self.is_str() || other.is_str() {
+            // facades over different allocations may hold the same content, so the pointer bytes
+            // say nothing about equality; compare the actual bytes instead
+            self.as_str().as_bytes() == other.as_str().as_bytes()
+        } else {
+            // by using an int type that covers all bytes the compiler can determine what
+            // the optimal bit-size to use on instruction level (best case its actually e.g. 128-bit
+            // cmp instruction)
+            self.to_scalar() == other.to_scalar()
+        }
     }
 }
 
@@ -688,3 +1998,95 @@ impl PartialEq<ShortStr<'_>> for &str {
         other.eq(self)
     }
 }
+
+impl Ord for ShortStr<'_> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.is_str() || other.is_str() {
+            // the pointer bytes of a facade have nothing to do with its content
+            self.as_str().cmp(other.as_str())
+        } else {
+            // lexicographic order on the inlined bytes as a single scalar comparison
+            self.inline_order_key().cmp(&other.inline_order_key())
+        }
+    }
+}
+
+impl PartialOrd<ShortStr<'_>> for ShortStr<'_> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &ShortStr) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialOrd<&str> for ShortStr<'_> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        // compare as scalar values through Ord for ShortStr
+        Some(self.cmp(&ShortStr::from_str(other)))
+    }
+}
+
+impl PartialOrd<ShortStr<'_>> for &str {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &ShortStr) -> Option<Ordering> {
+        // reuse PartialOrd<&str> for ShortStr
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+impl Borrow<str> for ShortStr<'_> {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Hash for ShortStr<'_> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // has to be identical to str for Borrow<str>
+        self.as_str().hash(state)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// A [`ShortStr`] with a faster, but [`str`] incompatible, [`Hash`] implementation. Inlined values
+/// are hashed as a single integer instead of byte by byte, facades are hashed by their bytes.
+///
+/// As the hash differs from the one of [`str`] it does not implement [`Borrow<str>`], meaning
+/// that lookups in e.g. a `HashMap<ScalarHash, _>` have to be done with another [`ScalarHash`].
+///
+/// # Examples
+/// ```
+/// use short_str::{ScalarHash, ShortStr};
+///
+/// let key = ScalarHash(ShortStr::from("key"));
+/// assert_eq!(key, ScalarHash(ShortStr::from("key")));
+/// assert_eq!(key.0, "key");
+/// ```
+pub struct ScalarHash<'str_lt>(pub ShortStr<'str_lt>);
+
+impl Hash for ScalarHash<'_> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // unchecked facades may hold inlinable content, which has to hash like the inlined value
+        // to be consistent with Eq
+        let short_str = match self.0.try_as_original() {
+            Some(original) => ShortStr::from_str(original),
+            None => self.0,
+        };
+
+        match short_str.try_as_original() {
+            Some(original) => original.hash(state),
+            None => short_str.to_scalar().hash(state),
+        }
+    }
+}
+
+impl<'str_lt> From<ShortStr<'str_lt>> for ScalarHash<'str_lt> {
+    #[inline(always)]
+    fn from(value: ShortStr<'str_lt>) -> Self {
+        Self(value)
+    }
+}
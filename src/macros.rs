@@ -0,0 +1,86 @@
+/// Produce a [`ShortStr<'static>`](crate::ShortStr) from a string literal (or any `&'static str`
+/// constant) at compile-time, usable in const items.
+///
+/// Literals that fit in [`INLINE_BYTE_SIZE`](crate::INLINE_BYTE_SIZE) bytes are inlined, while
+/// longer literals become facades of the literal. Use [`inline_str!`](crate::inline_str) when a
+/// literal is expected to be inlined, to fail compilation if it isn't.
+///
+/// # Examples
+/// These examples assume a little endian 64-bit architecture.
+/// ```
+/// use short_str::{short_str, ShortStr};
+///
+/// const INLINED: ShortStr = short_str!("hello");
+/// const FACADE: ShortStr = short_str!("this literal is too long to be inlined");
+/// assert_eq!(INLINED.is_str(), false);
+/// assert_eq!(FACADE.is_str(), true);
+/// ```
+#[macro_export]
+macro_rules! short_str {
+    ($literal:expr $(,)?) => {{
+        const VALUE: $crate::ShortStr<'static> = $crate::ShortStr::from_str($literal);
+        VALUE
+    }};
+}
+
+/// Produce an inlined [`ShortStr<'static>`](crate::ShortStr) from a string literal (or any
+/// `&'static str` constant) at compile-time, usable in const items.
+///
+/// Fails compilation if the literal is longer than [`INLINE_BYTE_SIZE`](crate::INLINE_BYTE_SIZE)
+/// bytes, see [`short_str!`] for literals of any length.
+///
+/// # Examples
+/// These examples assume a little endian 64-bit architecture.
+/// ```
+/// use short_str::{inline_str, ShortStr};
+///
+/// const INLINED: ShortStr = inline_str!("hello");
+/// assert_eq!(INLINED.is_str(), false);
+/// ```
+///
+/// ```compile_fail
+/// use short_str::{inline_str, ShortStr};
+///
+/// const TOO_LONG: ShortStr = inline_str!("this literal is too long to be inlined");
+/// ```
+#[macro_export]
+macro_rules! inline_str {
+    ($literal:expr $(,)?) => {{
+        const VALUE: $crate::ShortStr<'static> = $crate::short_str!($literal);
+        const _: () = $crate::__private::concat_assert!(
+            !VALUE.is_str(),
+            "expected \"",
+            $literal,
+            "\" (",
+            VALUE.len(),
+            " bytes) to fit in ",
+            $crate::INLINE_BYTE_SIZE,
+            " inlined bytes"
+        );
+        VALUE
+    }};
+}
+
+/// Format into an inlined [`ShortStr<'static>`](crate::ShortStr) like
+/// [`format!`](https://doc.rust-lang.org/std/macro.format.html), without allocating, see
+/// [`InlineBuilder`](crate::InlineBuilder).
+///
+/// Produces a [`CapacityError`](crate::CapacityError) if the formatted string is longer than
+/// [`INLINE_BYTE_SIZE`](crate::INLINE_BYTE_SIZE) bytes.
+///
+/// # Examples
+/// These examples assume a little endian 64-bit architecture.
+/// ```
+/// use short_str::{short_format, ShortStr};
+///
+/// let (prefix, id) = ("user", 42);
+/// let key: ShortStr = short_format!("{prefix}{id}").unwrap();
+/// assert_eq!(key, "user42");
+/// assert!(short_format!("{prefix} number {id} is too long").is_err());
+/// ```
+#[macro_export]
+macro_rules! short_format {
+    ($($arg:tt)*) => {
+        $crate::InlineBuilder::format(::core::format_args!($($arg)*)).map($crate::ShortStr::from)
+    };
+}
@@ -0,0 +1,495 @@
+use alloc::vec::Vec;
+use core::{fmt::Debug, iter::FusedIterator, mem::replace, slice};
+
+use crate::{CoveringInt, ShortStr};
+
+// Multiplier of the hash (the golden ratio as a fraction of 2^64), whose high bits are used to
+// pick a slot.
+const MIX: u64 = 0x9e37_79b9_7f4a_7c15;
+const MIN_CAPACITY: usize = 8;
+
+#[inline(always)]
+const fn mix(hash: u64, word: u64) -> u64 {
+    (hash.rotate_left(5) ^ word).wrapping_mul(MIX)
+}
+
+/// A map keyed by [`ShortStr`], using open addressing specialized for inlined keys. Inlined keys
+/// are hashed by mixing the integer of their bytes and compared with a single integer compare,
+/// while facades are hashed and compared by their bytes.
+///
+/// The hash isn't randomized, so keys controlled by an adversary can make operations slow.
+///
+/// # Examples
+/// These examples assume a little endian 64-bit architecture.
+/// ```
+/// use short_str::{ShortStr, ShortStrMap};
+///
+/// let mut counts = ShortStrMap::new();
+/// for word in "the cat saw the other cat".split(' ') {
+///     *counts.get_or_insert(ShortStr::from(word), 0) += 1;
+/// }
+/// assert_eq!(counts.get("cat"), Some(&2));
+/// assert_eq!(counts.get("dog"), None);
+/// ```
+#[derive(Clone)]
+pub struct ShortStrMap<'str_lt, V> {
+    // a power of two number of slots (or none), keys are canonical (see ShortStr::canonicalize)
+    // and stored in the first free slot from the one their hash picks
+    slots: Vec<Option<(ShortStr<'str_lt>, V)>>,
+    len: usize,
+}
+
+// A canonical key prepared for lookups.
+#[derive(Clone, Copy)]
+enum Query<'str_lt> {
+    Inlined(CoveringInt),
+    Facade(&'str_lt str),
+}
+
+impl<'str_lt> Query<'str_lt> {
+    #[inline(always)]
+    fn new(key: ShortStr<'str_lt>) -> Self {
+        match key.try_as_original() {
+            Some(original) => Query::Facade(original),
+            None => Query::Inlined(CoveringInt::from_ne_bytes(key.to_bytes())),
+        }
+    }
+
+    #[inline(always)]
+    fn hash(self) -> u64 {
+        match self {
+            Query::Inlined(scalar) => {
+                // widening covers the smaller integers on 32-bit platforms as well
+                #[allow(clippy::unnecessary_cast)]
+                let scalar = scalar as u128;
+                mix(mix(0, scalar as u64), (scalar >> 64) as u64)
+            }
+            Query::Facade(original) => {
+                let mut words = original.as_bytes().chunks_exact(8);
+                let mut hash = original.len() as u64;
+                for word in words.by_ref() {
+                    // safety:
+                    // chunks_exact produces slices of exactly 8 bytes
+                    let word = unsafe { word.try_into().unwrap_unchecked() };
+                    hash = mix(hash, u64::from_le_bytes(word));
+                }
+                let mut rest = [0; 8];
+                rest[..words.remainder().len()].copy_from_slice(words.remainder());
+                mix(hash, u64::from_le_bytes(rest))
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn matches(self, key: ShortStr) -> bool {
+        match self {
+            // canonical facades never share the marker of an inlined value
+            Query::Inlined(scalar) => CoveringInt::from_ne_bytes(key.to_bytes()) == scalar,
+            Query::Facade(original) => {
+                key.try_as_original().is_some_and(|key| key.as_bytes() == original.as_bytes())
+            }
+        }
+    }
+}
+
+impl<'str_lt, V> ShortStrMap<'str_lt, V> {
+    #[inline(always)]
+    /// Produce an empty [`ShortStrMap`], which doesn't allocate until a key is inserted.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrMap;
+    ///
+    /// let map: ShortStrMap<()> = ShortStrMap::new();
+    /// assert_eq!(map.len(), 0);
+    /// ```
+    pub const fn new() -> Self {
+        ShortStrMap {
+            slots: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Produce an empty [`ShortStrMap`] with space for at least `capacity` keys.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrMap;
+    ///
+    /// let map: ShortStrMap<()> = ShortStrMap::with_capacity(100);
+    /// assert!(map.capacity() >= 100);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut map = Self::new();
+        map.reserve(capacity);
+        map
+    }
+
+    #[inline(always)]
+    /// Returns the number of keys the [`ShortStrMap`] can hold without reallocating.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrMap;
+    ///
+    /// let map: ShortStrMap<()> = ShortStrMap::new();
+    /// assert_eq!(map.capacity(), 0);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        // at most three quarters of the slots are used
+        self.slots.len() / 4 * 3
+    }
+
+    #[inline(always)]
+    /// Returns the number of keys in the [`ShortStrMap`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{ShortStr, ShortStrMap};
+    ///
+    /// let mut map = ShortStrMap::new();
+    /// map.insert(ShortStr::from("key"), 1);
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    /// Returns `true` if the [`ShortStrMap`] holds no keys.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrMap;
+    ///
+    /// let map: ShortStrMap<()> = ShortStrMap::new();
+    /// assert_eq!(map.is_empty(), true);
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Make space for at least `additional` more keys in the [`ShortStrMap`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrMap;
+    ///
+    /// let mut map: ShortStrMap<()> = ShortStrMap::new();
+    /// map.reserve(10);
+    /// assert!(map.capacity() >= 10);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required <= self.capacity() {
+            return;
+        }
+
+        let slot_count = required
+            .checked_add(required / 3 + 1)
+            .and_then(usize::checked_next_power_of_two)
+            .expect("capacity overflow")
+            .max(MIN_CAPACITY);
+        let mut slots = Vec::with_capacity(slot_count);
+        slots.resize_with(slot_count, || None);
+        let slots = replace(&mut self.slots, slots);
+        for (key, value) in slots.into_iter().flatten() {
+            let index = self.probe(Query::new(key)).unwrap_err();
+            self.slots[index] = Some((key, value));
+        }
+    }
+
+    // Returns the index of the key matching `query` as Ok, or of the free slot it would be
+    // inserted in as Err. There has to be at least one free slot.
+    fn probe(&self, query: Query) -> Result<usize, usize> {
+        let mask = self.slots.len() - 1;
+        let mut index = self.slot_of(query);
+        loop {
+            match &self.slots[index] {
+                Some((key, _)) if query.matches(*key) => return Ok(index),
+                Some(_) => index = (index + 1) & mask,
+                None => return Err(index),
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn slot_of(&self, query: Query) -> usize {
+        // the high bits of the hash are the best mixed
+        let bits = self.slots.len().trailing_zeros();
+        match bits {
+            0 => 0,
+            _ => (query.hash() >> (u64::BITS - bits)) as usize,
+        }
+    }
+
+    #[inline(always)]
+    fn find(&self, key: &str) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+        self.probe(Query::new(ShortStr::from_str(key))).ok()
+    }
+
+    /// Insert `value` under `key`, returning the value it replaces if `key` was already in the
+    /// [`ShortStrMap`]. The original key is kept in that case.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{ShortStr, ShortStrMap};
+    ///
+    /// let mut map = ShortStrMap::new();
+    /// assert_eq!(map.insert(ShortStr::from("key"), 1), None);
+    /// assert_eq!(map.insert(ShortStr::from("key"), 2), Some(1));
+    /// assert_eq!(map.get("key"), Some(&2));
+    /// ```
+    pub fn insert(&mut self, key: ShortStr<'str_lt>, value: V) -> Option<V> {
+        self.reserve(1);
+        let key = key.canonicalize();
+        match self.probe(Query::new(key)) {
+            Ok(index) => {
+                // safety:
+                // probe only returns Ok for occupied slots
+                let (_, old) = unsafe { self.slots[index].as_mut().unwrap_unchecked() };
+                Some(replace(old, value))
+            }
+            Err(index) => {
+                self.slots[index] = Some((key, value));
+                self.len += 1;
+                None
+            }
+        }
+    }
+
+    /// Returns the value under `key`, inserting `default` under it first if it's missing.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{ShortStr, ShortStrMap};
+    ///
+    /// let mut map = ShortStrMap::new();
+    /// *map.get_or_insert(ShortStr::from("key"), 1) += 1;
+    /// *map.get_or_insert(ShortStr::from("key"), 1) += 1;
+    /// assert_eq!(map.get("key"), Some(&3));
+    /// ```
+    pub fn get_or_insert(&mut self, key: ShortStr<'str_lt>, default: V) -> &mut V {
+        self.reserve(1);
+        let key = key.canonicalize();
+        let index = match self.probe(Query::new(key)) {
+            Ok(index) => index,
+            Err(index) => {
+                self.slots[index] = Some((key, default));
+                self.len += 1;
+                index
+            }
+        };
+        // safety:
+        // the slot is either occupied by key, or was just filled
+        let (_, value) = unsafe { self.slots[index].as_mut().unwrap_unchecked() };
+        value
+    }
+
+    #[inline(always)]
+    /// Returns the value under `key`. The query is converted through [`ShortStr::from_str`],
+    /// so an inlinable `key` is compared as a single integer without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{ShortStr, ShortStrMap};
+    ///
+    /// let mut map = ShortStrMap::new();
+    /// map.insert(ShortStr::from("key"), 1);
+    /// assert_eq!(map.get("key"), Some(&1));
+    /// assert_eq!(map.get("other"), None);
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&V> {
+        let index = self.find(key)?;
+        self.slots[index].as_ref().map(|(_, value)| value)
+    }
+
+    #[inline(always)]
+    /// Returns the key and value under `key`, the key being the one originally inserted.
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::{ShortStr, ShortStrMap};
+    ///
+    /// let long = "this string is too long to be inlined";
+    /// let mut map = ShortStrMap::new();
+    /// map.insert(ShortStr::from(long), 1);
+    /// let query = String::from(long);
+    /// let (key, _) = map.get_key_value(&query).unwrap();
+    /// assert_eq!(key.as_ptr(), long.as_ptr());
+    /// ```
+    pub fn get_key_value(&self, key: &str) -> Option<(ShortStr<'str_lt>, &V)> {
+        let index = self.find(key)?;
+        self.slots[index].as_ref().map(|(key, value)| (*key, value))
+    }
+
+    #[inline(always)]
+    /// Returns the value under `key` mutably.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{ShortStr, ShortStrMap};
+    ///
+    /// let mut map = ShortStrMap::new();
+    /// map.insert(ShortStr::from("key"), 1);
+    /// *map.get_mut("key").unwrap() = 2;
+    /// assert_eq!(map.get("key"), Some(&2));
+    /// ```
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        let index = self.find(key)?;
+        self.slots[index].as_mut().map(|(_, value)| value)
+    }
+
+    #[inline(always)]
+    /// Returns `true` if the [`ShortStrMap`] holds `key`.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{ShortStr, ShortStrMap};
+    ///
+    /// let mut map = ShortStrMap::new();
+    /// map.insert(ShortStr::from("key"), 1);
+    /// assert!(map.contains_key("key"));
+    /// ```
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// Remove `key` from the [`ShortStrMap`], returning its value if it was there.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{ShortStr, ShortStrMap};
+    ///
+    /// let mut map = ShortStrMap::new();
+    /// map.insert(ShortStr::from("key"), 1);
+    /// assert_eq!(map.remove("key"), Some(1));
+    /// assert_eq!(map.remove("key"), None);
+    /// ```
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        let mut index = self.find(key)?;
+        let (_, value) = self.slots[index].take()?;
+        self.len -= 1;
+
+        // shift back the keys after the removed one that would otherwise be unreachable, as
+        // probing stops at the first free slot
+        let mask = self.slots.len() - 1;
+        let mut next = (index + 1) & mask;
+        while let Some((key, _)) = &self.slots[next] {
+            let slot = self.slot_of(Query::new(*key));
+            // whether the slot of the key is cyclically outside of (index, next]
+            if (next.wrapping_sub(slot) & mask) >= (next.wrapping_sub(index) & mask) {
+                self.slots[index] = self.slots[next].take();
+                index = next;
+            }
+            next = (next + 1) & mask;
+        }
+        Some(value)
+    }
+
+    /// Remove every key from the [`ShortStrMap`], keeping its capacity.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{ShortStr, ShortStrMap};
+    ///
+    /// let mut map = ShortStrMap::new();
+    /// map.insert(ShortStr::from("key"), 1);
+    /// map.clear();
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+        self.len = 0;
+    }
+
+    #[inline(always)]
+    /// Returns an iterator over the keys and values of the [`ShortStrMap`], in no particular
+    /// order.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{ShortStr, ShortStrMap};
+    ///
+    /// let map: ShortStrMap<_> = [(ShortStr::from("key"), 1)].into_iter().collect();
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), [(ShortStr::from("key"), &1)]);
+    /// ```
+    pub fn iter(&self) -> ShortStrMapIter<'_, 'str_lt, V> {
+        ShortStrMapIter {
+            slots: self.slots.iter(),
+            len: self.len,
+        }
+    }
+}
+
+impl<V> Default for ShortStrMap<'_, V> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Debug> Debug for ShortStrMap<'_, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'str_lt, V> FromIterator<(ShortStr<'str_lt>, V)> for ShortStrMap<'str_lt, V> {
+    fn from_iter<T: IntoIterator<Item = (ShortStr<'str_lt>, V)>>(iter: T) -> Self {
+        let mut map = ShortStrMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<'str_lt, V> Extend<(ShortStr<'str_lt>, V)> for ShortStrMap<'str_lt, V> {
+    fn extend<T: IntoIterator<Item = (ShortStr<'str_lt>, V)>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'map, 'str_lt, V> IntoIterator for &'map ShortStrMap<'str_lt, V> {
+    type Item = (ShortStr<'str_lt>, &'map V);
+    type IntoIter = ShortStrMapIter<'map, 'str_lt, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the keys and values of a [`ShortStrMap`], see [`ShortStrMap::iter`].
+#[derive(Clone, Debug)]
+pub struct ShortStrMapIter<'map, 'str_lt, V> {
+    slots: slice::Iter<'map, Option<(ShortStr<'str_lt>, V)>>,
+    // keys left to produce
+    len: usize,
+}
+
+impl<'map, 'str_lt, V> Iterator for ShortStrMapIter<'map, 'str_lt, V> {
+    type Item = (ShortStr<'str_lt>, &'map V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.slots.by_ref().flatten().next()?;
+        self.len -= 1;
+        Some((*key, value))
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<V> ExactSizeIterator for ShortStrMapIter<'_, '_, V> {}
+
+impl<V> FusedIterator for ShortStrMapIter<'_, '_, V> {}
@@ -0,0 +1,70 @@
+use core::fmt::Formatter;
+
+use serde::{
+    de::{Error, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{CapacityError, ShortStr, INLINE_BYTE_SIZE};
+
+impl Serialize for ShortStr<'_> {
+    #[inline(always)]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+// Borrows the deserialized string when possible, and only accepts a transient one (e.g. when
+// reading from a stream, or a string with escapes in JSON) if it can be inlined.
+struct ShortStrVisitor;
+
+impl<'de> Visitor<'de> for ShortStrVisitor {
+    type Value = ShortStr<'de>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        write!(
+            formatter,
+            "a borrowed string, or a string of at most {INLINE_BYTE_SIZE} bytes"
+        )
+    }
+
+    #[inline(always)]
+    fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(ShortStr::from_str(v))
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        match ShortStr::from_str(v).try_into_static() {
+            Some(inlined) => Ok(inlined),
+            None => Err(E::custom(format_args!(
+                "{}, and a ShortStr can only borrow strings that are not transient (e.g. not \
+                 read from a stream or unescaped)",
+                CapacityError { len: v.len() }
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ShortStr<'de> {
+    #[inline(always)]
+    /// Deserialize a [`ShortStr`] borrowing from the input, like a [`&str`] would. A string that
+    /// can't be borrowed (e.g. when reading from a stream) is only accepted if it can be inlined.
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use serde::Deserialize;
+    /// use short_str::ShortStr;
+    ///
+    /// let json = r#"["hello", "a string that is too long to be inlined"]"#;
+    /// let [hello, long]: [ShortStr; 2] = serde_json::from_str(json).unwrap();
+    /// assert_eq!(hello, "hello");
+    /// assert_eq!(long.is_str(), true);
+    ///
+    /// let mut reader = serde_json::Deserializer::from_reader(json.as_bytes());
+    /// assert!(<[ShortStr; 2]>::deserialize(&mut reader).is_err());
+    /// ```
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ShortStrVisitor)
+    }
+}
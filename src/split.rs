@@ -0,0 +1,323 @@
+use core::iter::FusedIterator;
+
+use crate::ShortStr;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A pattern to search for in a [`ShortStr`], mirroring the (unstable) [`core::str::pattern`]
+/// patterns: a [`char`], a [`&str`], a slice or array of [`char`]s, or a `FnMut(char) -> bool`.
+///
+/// This trait is sealed and can't be implemented outside of this crate.
+///
+/// # Examples
+/// ```
+/// use short_str::ShortStr;
+///
+/// let string = ShortStr::from("a,b;c");
+/// assert_eq!(string.split_once(','), string.split_once(","));
+/// assert_eq!(string.split_once([',', ';']), string.split_once(|c| c == ','));
+/// ```
+pub trait ShortPattern: private::Sealed {
+    /// Returns the byte range of the first match of the pattern in `haystack`.
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns the byte range of the last match of the pattern in `haystack`.
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns the length of the match of the pattern at the start of `haystack`.
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize>;
+
+    /// Returns the length of the match of the pattern at the end of `haystack`.
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize>;
+}
+
+impl private::Sealed for char {}
+
+impl ShortPattern for char {
+    #[inline(always)]
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let start = haystack.find(*self)?;
+        Some((start, start + self.len_utf8()))
+    }
+
+    #[inline(always)]
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let start = haystack.rfind(*self)?;
+        Some((start, start + self.len_utf8()))
+    }
+
+    #[inline(always)]
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.starts_with(*self).then_some(self.len_utf8())
+    }
+
+    #[inline(always)]
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.ends_with(*self).then_some(self.len_utf8())
+    }
+}
+
+impl private::Sealed for &str {}
+
+impl ShortPattern for &str {
+    #[inline(always)]
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let start = haystack.find(*self)?;
+        Some((start, start + self.len()))
+    }
+
+    #[inline(always)]
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let start = haystack.rfind(*self)?;
+        Some((start, start + self.len()))
+    }
+
+    #[inline(always)]
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.starts_with(*self).then_some(self.len())
+    }
+
+    #[inline(always)]
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.ends_with(*self).then_some(self.len())
+    }
+}
+
+impl private::Sealed for &[char] {}
+
+impl ShortPattern for &[char] {
+    #[inline(always)]
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        (|c: char| self.contains(&c)).find_in(haystack)
+    }
+
+    #[inline(always)]
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        (|c: char| self.contains(&c)).rfind_in(haystack)
+    }
+
+    #[inline(always)]
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        (|c: char| self.contains(&c)).prefix_len(haystack)
+    }
+
+    #[inline(always)]
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        (|c: char| self.contains(&c)).suffix_len(haystack)
+    }
+}
+
+impl<const N: usize> private::Sealed for [char; N] {}
+
+impl<const N: usize> ShortPattern for [char; N] {
+    #[inline(always)]
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_slice().find_in(haystack)
+    }
+
+    #[inline(always)]
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_slice().rfind_in(haystack)
+    }
+
+    #[inline(always)]
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.as_slice().prefix_len(haystack)
+    }
+
+    #[inline(always)]
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.as_slice().suffix_len(haystack)
+    }
+}
+
+impl<const N: usize> private::Sealed for &[char; N] {}
+
+impl<const N: usize> ShortPattern for &[char; N] {
+    #[inline(always)]
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_slice().find_in(haystack)
+    }
+
+    #[inline(always)]
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_slice().rfind_in(haystack)
+    }
+
+    #[inline(always)]
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.as_slice().prefix_len(haystack)
+    }
+
+    #[inline(always)]
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.as_slice().suffix_len(haystack)
+    }
+}
+
+impl<F: FnMut(char) -> bool> private::Sealed for F {}
+
+impl<F: FnMut(char) -> bool> ShortPattern for F {
+    #[inline(always)]
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let (start, c) = haystack.char_indices().find(|&(_, c)| self(c))?;
+        Some((start, start + c.len_utf8()))
+    }
+
+    #[inline(always)]
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        let (start, c) = haystack.char_indices().rfind(|&(_, c)| self(c))?;
+        Some((start, start + c.len_utf8()))
+    }
+
+    #[inline(always)]
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        let c = haystack.chars().next()?;
+        self(c).then_some(c.len_utf8())
+    }
+
+    #[inline(always)]
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        let c = haystack.chars().next_back()?;
+        self(c).then_some(c.len_utf8())
+    }
+}
+
+/// An iterator over the pieces of a [`ShortStr`] separated by a pattern, see
+/// [`ShortStr::split`].
+#[derive(Clone, Debug)]
+pub struct Split<'str_lt, P> {
+    source: ShortStr<'str_lt>,
+    pattern: P,
+    // start of the next piece
+    start: usize,
+    // where to search for the next match, past start after an empty match
+    position: usize,
+    finished: bool,
+}
+
+impl<'str_lt, P: ShortPattern> Split<'str_lt, P> {
+    #[inline(always)]
+    pub(crate) fn new(source: ShortStr<'str_lt>, pattern: P) -> Self {
+        Split {
+            source,
+            pattern,
+            start: 0,
+            position: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<'str_lt, P: ShortPattern> Iterator for Split<'str_lt, P> {
+    type Item = ShortStr<'str_lt>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let haystack = self.source.as_str();
+        let found = match haystack.get(self.position..) {
+            Some(rest) => self.pattern.find_in(rest),
+            None => None,
+        };
+        let (start, end) = match found {
+            Some((start, end)) => (self.position + start, self.position + end),
+            None => {
+                self.finished = true;
+                let end = haystack.len();
+                // safety:
+                // start is always a char boundary within the source
+                return Some(unsafe { self.source.slice_range_unchecked(self.start, end) });
+            }
+        };
+
+        // safety:
+        // the match is within the source and at char boundaries, past start
+        let piece = unsafe { self.source.slice_range_unchecked(self.start, start) };
+        self.start = end;
+        // an empty match would be found again, so search past the next char (like &str does)
+        self.position = match haystack[end..].chars().next() {
+            _ if start != end => end,
+            Some(c) => end + c.len_utf8(),
+            None => end + 1,
+        };
+        Some(piece)
+    }
+}
+
+impl<P: ShortPattern> FusedIterator for Split<'_, P> {}
+
+/// An iterator over the lines of a [`ShortStr`], see [`ShortStr::lines`].
+#[derive(Clone, Debug)]
+pub struct Lines<'str_lt> {
+    source: ShortStr<'str_lt>,
+    position: usize,
+}
+
+impl<'str_lt> Lines<'str_lt> {
+    #[inline(always)]
+    pub(crate) fn new(source: ShortStr<'str_lt>) -> Self {
+        Lines { source, position: 0 }
+    }
+}
+
+impl<'str_lt> Iterator for Lines<'str_lt> {
+    type Item = ShortStr<'str_lt>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let haystack = self.source.as_str();
+        let start = self.position;
+        if start >= haystack.len() {
+            return None;
+        }
+
+        let end = match haystack[start..].find('\n') {
+            Some(index) => {
+                let end = start + index;
+                self.position = end + 1;
+                // only strip a carriage return right before the line feed, like &str does
+                if haystack[start..end].ends_with('\r') { end - 1 } else { end }
+            }
+            None => {
+                self.position = haystack.len();
+                haystack.len()
+            }
+        };
+
+        // safety:
+        // start and end are within the source and next to ascii characters (or the ends)
+        Some(unsafe { self.source.slice_range_unchecked(start, end) })
+    }
+}
+
+impl FusedIterator for Lines<'_> {}
+
+/// An iterator over the non-whitespace pieces of a [`ShortStr`], see
+/// [`ShortStr::split_whitespace`].
+#[derive(Clone, Debug)]
+pub struct SplitWhitespace<'str_lt> {
+    inner: Split<'str_lt, fn(char) -> bool>,
+}
+
+impl<'str_lt> SplitWhitespace<'str_lt> {
+    #[inline(always)]
+    pub(crate) fn new(source: ShortStr<'str_lt>) -> Self {
+        SplitWhitespace {
+            inner: Split::new(source, char::is_whitespace),
+        }
+    }
+}
+
+impl<'str_lt> Iterator for SplitWhitespace<'str_lt> {
+    type Item = ShortStr<'str_lt>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.by_ref().find(|piece| !piece.is_empty())
+    }
+}
+
+impl FusedIterator for SplitWhitespace<'_> {}
@@ -0,0 +1,195 @@
+use alloc::{boxed::Box, string::String};
+use core::{convert::Infallible, ptr::slice_from_raw_parts_mut, str::FromStr};
+
+use crate::ShortStr;
+
+/// An owned [`ShortStr`], which inlines its content the same way but allocates on the heap when
+/// the content doesn't fit, instead of borrowing it.
+///
+/// # Examples
+/// ```
+/// use short_str::{ShortStr, ShortString};
+///
+/// let inlined = ShortString::from("hello");
+/// let allocated = ShortString::from("stronger, faster, better, morer?");
+/// assert_eq!(inlined.as_short_str().is_str(), false);
+/// assert_eq!(allocated.as_short_str().is_str(), true);
+/// ```
+pub struct ShortString {
+    // either inlined, or a facade of a Box<str> owned by this ShortString
+    repr: ShortStr<'static>,
+}
+
+impl ShortString {
+    /// An empty [`ShortString`], which never allocates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use short_str::ShortString;
+    ///
+    /// assert_eq!(ShortString::EMPTY, "");
+    /// ```
+    pub const EMPTY: ShortString = ShortString { repr: ShortStr::EMPTY };
+
+    #[inline(always)]
+    /// Produce a [`ShortString`] from an owned [`Box<str>`], reusing its allocation if the
+    /// content can't be inlined.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortString;
+    ///
+    /// let string = ShortString::from_boxed_str("this string is way too long to be inlined".into());
+    /// assert_eq!(string, "this string is way too long to be inlined");
+    /// ```
+    pub fn from_boxed_str(value: Box<str>) -> Self {
+        Self::inline_or_box(value)
+    }
+
+    #[inline(always)]
+    /// Borrow the [`ShortString`] as a [`ShortStr`]. Inlined content is copied over, while
+    /// allocated content produces a facade of the allocation.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{ShortStr, ShortString};
+    ///
+    /// let string = ShortString::from("hello");
+    /// let short: ShortStr = string.as_short_str();
+    /// assert_eq!(short, "hello");
+    /// ```
+    pub const fn as_short_str(&self) -> ShortStr<'_> {
+        self.repr
+    }
+
+    #[inline(always)]
+    /// View the [`ShortString`] as a [`&str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortString;
+    ///
+    /// let string = ShortString::from("hello");
+    /// assert_eq!(string.as_str(), "hello");
+    /// ```
+    pub const fn as_str(&self) -> &str {
+        self.repr.as_str()
+    }
+
+    #[inline(always)]
+    /// Returns if the content of the [`ShortString`] is allocated (not inlined) or not.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortString;
+    ///
+    /// assert_eq!(ShortString::from("i am too big to fit inside the structure").is_heap(), true);
+    /// assert_eq!(ShortString::from("small").is_heap(), false);
+    /// ```
+    pub const fn is_heap(&self) -> bool {
+        self.repr.is_str()
+    }
+
+    #[inline(always)]
+    /// Returns the number of bytes in the [`ShortString`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortString;
+    ///
+    /// assert_eq!(ShortString::from("hello").len(), 5);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.repr.len()
+    }
+
+    #[inline(always)]
+    /// Returns `true` if the [`ShortString`] has a length of zero bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortString;
+    ///
+    /// assert_eq!(ShortString::EMPTY.is_empty(), true);
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.repr.is_empty()
+    }
+
+    #[inline(always)]
+    /// Inline `value` if it fits, or otherwise take it as a [`Box<str>`], which only allocates
+    /// if `value` doesn't own one already.
+    fn inline_or_box<S: AsRef<str> + Into<Box<str>>>(value: S) -> Self {
+        match ShortStr::from_str(value.as_ref()).try_into_static() {
+            Some(repr) => ShortString { repr },
+            None => {
+                let value: Box<str> = value.into();
+                let len = value.len();
+                let ptr = Box::into_raw(value);
+                // safety:
+                // the allocation is owned by the ShortString until dropped, and isn't inlined
+                let repr = unsafe { ShortStr::from_raw_parts(ptr.cast::<u8>(), len) };
+                ShortString { repr }
+            }
+        }
+    }
+}
+
+impl Drop for ShortString {
+    fn drop(&mut self) {
+        if self.repr.is_str() {
+            let ptr = slice_from_raw_parts_mut(self.repr.ptr.cast_mut(), self.repr.len());
+            // safety:
+            // facades of a ShortString are always created from Box::into_raw in inline_or_box,
+            // with the pointer stored as is
+            drop(unsafe { Box::from_raw(ptr as *mut str) });
+        }
+    }
+}
+
+impl Clone for ShortString {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self::from(self.as_str())
+    }
+}
+
+impl From<&str> for ShortString {
+    #[inline(always)]
+    fn from(value: &str) -> Self {
+        Self::inline_or_box(value)
+    }
+}
+
+impl From<ShortStr<'_>> for ShortString {
+    #[inline(always)]
+    fn from(value: ShortStr<'_>) -> Self {
+        Self::inline_or_box(value.as_str())
+    }
+}
+
+impl From<String> for ShortString {
+    #[inline(always)]
+    fn from(value: String) -> Self {
+        Self::inline_or_box(value)
+    }
+}
+
+impl From<Box<str>> for ShortString {
+    #[inline(always)]
+    fn from(value: Box<str>) -> Self {
+        Self::inline_or_box(value)
+    }
+}
+
+impl FromStr for ShortString {
+    type Err = Infallible;
+
+    #[inline(always)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+crate::impl_str_like!(ShortString);
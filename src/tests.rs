@@ -1,9 +1,129 @@
 use core::mem::transmute;
 
-use crate::{ShStr, ShortStr, BYTE_SIZE};
+use crate::{ShStr, ShortStr, BYTE_SIZE, INLINE_BYTE_SIZE};
+
+/// Too long to be inlined on any layout, ascii only so that it can be sliced anywhere
+const FACADE: &str = "a string that is too long to be inlined";
+/// Exactly fills the inline capacity of the current layout
+const INLINED: &str = inline_prefix(FACADE);
+
+/// Too long to be inlined on any layout, with multibyte chars of every width within the first
+/// INLINE_BYTE_SIZE bytes of any layout (4, 7, 8 or 15), which all end on a char boundary
+const MULTIBYTE_FACADE: &str = "éé€s🈁 string that is too long 🈁 to be inlined";
+/// Exactly fills the inline capacity of the current layout, starting with "éé"
+const MULTIBYTE_INLINED: &str = inline_prefix(MULTIBYTE_FACADE);
+
+/// `&string[..INLINE_BYTE_SIZE]` usable in const items
+const fn inline_prefix(string: &'static str) -> &'static str {
+    let (bytes, _) = string.as_bytes().split_at(INLINE_BYTE_SIZE);
+    match core::str::from_utf8(bytes) {
+        Ok(prefix) => prefix,
+        Err(_) => panic!("expected a char boundary at INLINE_BYTE_SIZE"),
+    }
+}
+
+/// Returns an index inside of the last multibyte char of `string`, which isn't a char boundary
+fn inside_last_char(string: &str) -> usize {
+    let (index, _) = string.char_indices().rfind(|(_, c)| c.len_utf8() > 1).unwrap();
+    index + 1
+}
+
+/// Strings of every length up to past the inline capacity, some of them repeated, followed by
+/// longer ones up to past the chunk size of an `Arena`
+#[cfg(feature = "alloc")]
+fn strings() -> alloc::vec::Vec<alloc::string::String> {
+    use alloc::{format, string::String};
+
+    (0..=INLINE_BYTE_SIZE + 1)
+        .flat_map(|len| [&FACADE[..len], &FACADE[1..=len], &FACADE[..len]])
+        .chain([FACADE, &FACADE[1..], "ŝŝŝ"])
+        .map(String::from)
+        .chain((0..500).map(|i| format!("{i}").repeat(i % (INLINE_BYTE_SIZE + 3) / 2 + 1)))
+        .chain(["x".repeat(5000), "y".repeat(100_000)])
+        .collect()
+}
 
 mod assumptions {
-    use crate::{CoveringInt, ShStr};
+    use crate::{CoveringInt, ShStr, ShortStr, INLINE_BYTE_SIZE, INLINE_FLAG, MARKER_INDEX};
+
+    use super::{FACADE, INLINED, MULTIBYTE_FACADE, MULTIBYTE_INLINED};
+
+    #[test]
+    /// Verify that the marker is the most significant byte of the length, for either endianness
+    fn verify_marker_is_length_msb() {
+        for string in ["\0", "a", "hello", "fifteen bytes!!"] {
+            let short = ShortStr::from(string);
+            if short.is_str() {
+                continue;
+            }
+            let [_, len] = unsafe { core::mem::transmute::<ShortStr, [usize; 2]>(short) };
+            let marker = (len >> (usize::BITS - 8)) as u8;
+            assert_eq!(marker, INLINE_FLAG | string.len() as u8, "{string:?}");
+        }
+        let [_, len] = unsafe { core::mem::transmute::<ShortStr, [usize; 2]>(ShortStr::EMPTY) };
+        assert_eq!(len >> (usize::BITS - 8), 0xff);
+    }
+
+    #[test]
+    /// Verify that inlined bytes start at the first byte and don't overlap the marker
+    fn verify_inline_bytes() {
+        let string = core::str::from_utf8(&[b'x'; INLINE_BYTE_SIZE]).unwrap();
+        let short = ShortStr::from(string);
+        assert!(!short.is_str());
+        assert_eq!(&short.to_bytes()[..INLINE_BYTE_SIZE], string.as_bytes());
+        assert_eq!(short.to_bytes()[MARKER_INDEX], INLINE_FLAG | INLINE_BYTE_SIZE as u8);
+    }
+
+    #[test]
+    #[cfg(target_endian = "big")]
+    /// Verify that big endian only inlines into the pointer bytes, with the marker right after
+    fn verify_big_endian_layout() {
+        use crate::PTR_SIZE;
+
+        assert_eq!(INLINE_BYTE_SIZE, PTR_SIZE);
+        assert_eq!(MARKER_INDEX, PTR_SIZE);
+    }
+
+    #[test]
+    #[cfg(feature = "pointer-storage")]
+    /// Verify that pointer-storage only inlines into the pointer bytes, leaving the length alone
+    fn verify_pointer_storage_layout() {
+        use crate::PTR_SIZE;
+
+        assert_eq!(INLINE_BYTE_SIZE, PTR_SIZE);
+        let string = core::str::from_utf8(&[b'x'; INLINE_BYTE_SIZE]).unwrap();
+        let [_, len] = unsafe { core::mem::transmute::<ShortStr, [usize; 2]>(ShortStr::from(string)) };
+        assert_eq!(len, (INLINE_FLAG as usize | INLINE_BYTE_SIZE) << (usize::BITS - 8));
+    }
+
+    #[test]
+    /// Verify that the shared fixtures are inlined and facades on the current layout
+    fn verify_fixtures() {
+        for (inlined, facade) in [(INLINED, FACADE), (MULTIBYTE_INLINED, MULTIBYTE_FACADE)] {
+            assert_eq!(inlined.len(), INLINE_BYTE_SIZE);
+            assert!(!ShortStr::from(inlined).is_str());
+            assert!(ShortStr::from(facade).is_str());
+        }
+    }
+
+    #[test]
+    /// Verify that the length of any &str is never mistaken for an inlined value
+    fn verify_facade_length_never_flagged() {
+        let short = ShortStr::from(FACADE);
+        assert!(short.is_str());
+        let [_, len] = unsafe { core::mem::transmute::<ShortStr, [usize; 2]>(short) };
+        assert_eq!(len, FACADE.len());
+    }
+
+    #[test]
+    #[cfg(all(target_endian = "little", not(feature = "pointer-storage")))]
+    /// Verify that little endian inlines into all but the last byte
+    fn verify_little_endian_layout() {
+        use crate::{BYTE_SIZE, PTR_SIZE};
+
+        assert_eq!(INLINE_BYTE_SIZE, 2 * PTR_SIZE - 1);
+        assert_eq!(MARKER_INDEX, BYTE_SIZE - 1);
+    }
 
     #[test]
     /// Verify that layout is indeed ptr then len
@@ -13,6 +133,13 @@ mod assumptions {
         assert_eq!(len, 4);
     }
 
+    #[test]
+    /// Verify that Option<ShortStr> makes use of the niche, like Option<&str>
+    fn verify_option_size() {
+        assert_eq!(size_of::<Option<ShortStr>>(), size_of::<ShortStr>());
+        assert_eq!(size_of::<Option<ShortStr>>(), size_of::<Option<&str>>());
+    }
+
     #[test]
     /// Verify that size is indeed 2x usize
     fn verify_size() {
@@ -53,7 +180,7 @@ macro_rules! str_assert {
                 "\n        ({__data_marker__})",
             ),
             $($($x,)+)?
-            __data_slice__ = &$short_str.data[..BYTE_SIZE - 1],
+            __data_slice__ = &$short_str.to_bytes()[..BYTE_SIZE - 1],
             __data_slice_length__ = BYTE_SIZE - 1,
             __data_marker__ = $short_str.length_marker(),
         );
@@ -75,7 +202,7 @@ macro_rules! str_assert_eq {
                 "\n        ({__data_marker__})",
             ),
             $($($x,)+)?
-            __data_slice__ = &$short_str.data[..BYTE_SIZE - 1],
+            __data_slice__ = &$short_str.to_bytes()[..BYTE_SIZE - 1],
             __data_slice_length__ = BYTE_SIZE - 1,
             __data_marker__ = $short_str.length_marker(),
         );
@@ -98,9 +225,9 @@ macro_rules! str_assert_eq {
             ),
             $($($x,)+)?
             __data_slice_length__ = BYTE_SIZE - 1,
-            __data_slice_a__ = &$a_shstr.data[..BYTE_SIZE - 1],
+            __data_slice_a__ = &$a_shstr.to_bytes()[..BYTE_SIZE - 1],
             __data_marker_a__ = $a_shstr.length_marker(),
-            __data_slice_b__ = &$b_shstr.data[..BYTE_SIZE - 1],
+            __data_slice_b__ = &$b_shstr.to_bytes()[..BYTE_SIZE - 1],
             __data_marker_b__ = $b_shstr.length_marker(),
         );
     };
@@ -176,37 +303,37 @@ fn empty_str_inline() {
 #[test]
 fn range_from_into_range() {
     let short = ShStr::from("abc");
-    assert_eq!(short.bounds_to_range(1..), 1..3);
+    assert_eq!(short.bounds_to_range(1..), Ok(1..3));
 }
 
 #[test]
 fn range_to_into_range() {
     let short = ShStr::from("abc");
-    assert_eq!(short.bounds_to_range(..1), 0..1);
+    assert_eq!(short.bounds_to_range(..1), Ok(0..1));
 }
 
 #[test]
 fn range_into_range() {
     let short = ShStr::from("abc");
-    assert_eq!(short.bounds_to_range(0..1), 0..1);
+    assert_eq!(short.bounds_to_range(0..1), Ok(0..1));
 }
 
 #[test]
 fn range_full_into_range() {
     let short = ShStr::from("abc");
-    assert_eq!(short.bounds_to_range(..), 0..3);
+    assert_eq!(short.bounds_to_range(..), Ok(0..3));
 }
 
 #[test]
 fn range_to_inclusive_into_range() {
     let short = ShStr::from("abc");
-    assert_eq!(short.bounds_to_range(..=3), 0..4);
+    assert_eq!(short.bounds_to_range(..=3), Ok(0..4));
 }
 
 #[test]
 fn range_inclusive_into_range() {
     let short = ShStr::from("abc");
-    assert_eq!(short.bounds_to_range(1..=3), 1..4);
+    assert_eq!(short.bounds_to_range(1..=3), Ok(1..4));
 }
 
 #[test]
@@ -225,6 +352,7 @@ fn oversized_slice_start_panics() {
 
 #[test]
 #[should_panic]
+#[allow(clippy::reversed_empty_ranges)]
 fn descending_slice_panics() {
     let short = ShStr::from("abc");
     let _ = short.slice(4..1);
@@ -270,7 +398,7 @@ fn inline_str_range_from_slice_value() {
 fn inline_str_range_to_slice_length() {
     let range = ..1;
     let a = ShStr::from("abc");
-    let b = a.slice(range.clone());
+    let b = a.slice(range);
     str_assert_eq!(
         b.len(),
         a.len() - 2,
@@ -292,7 +420,7 @@ fn inline_str_range_to_slice_length() {
 fn inline_str_range_to_slice_value() {
     let range = ..1;
     let a = ShStr::from("abc");
-    let b = a.slice(range.clone());
+    let b = a.slice(range);
     str_assert_eq!(
         b.as_str(),
         &a.as_str()[range],
@@ -319,3 +447,1540 @@ fn inline_str_zero_len_slice_becomes_zero_len_shortstr() {
         "expected zero length slice of ShortStr equal to zero length/empty/special ShortStr"
     );
 }
+
+mod equality {
+    use crate::ShortStr;
+
+    use super::{FACADE, INLINED};
+
+    #[test]
+    fn inlined_eq_inlined() {
+        let a: [u8; INLINED.len()] = INLINED.as_bytes().try_into().unwrap();
+        let b = a;
+        let a = ShortStr::from(core::str::from_utf8(&a).unwrap());
+        let b = ShortStr::from(core::str::from_utf8(&b).unwrap());
+        assert!(!a.is_str() && !b.is_str());
+        assert_eq!(a, b);
+        assert_ne!(a, ShortStr::from(&INLINED[1..]));
+    }
+
+    #[test]
+    fn facade_eq_facade_different_allocations() {
+        let a: [u8; FACADE.len()] = FACADE.as_bytes().try_into().unwrap();
+        let b = a;
+        let a = ShortStr::from(core::str::from_utf8(&a).unwrap());
+        let b = ShortStr::from(core::str::from_utf8(&b).unwrap());
+        assert!(a.is_str() && b.is_str());
+        assert_ne!(a.as_ptr(), b.as_ptr());
+        assert_eq!(a, b);
+        assert_ne!(a, ShortStr::from(&FACADE[1..]));
+    }
+
+    #[test]
+    fn facade_eq_inlined() {
+        // only reachable through the unchecked constructor, as from_str inlines short values
+        let facade = unsafe { ShortStr::from_str_unchecked(INLINED) };
+        let inlined = ShortStr::from(INLINED);
+        assert!(facade.is_str() && !inlined.is_str());
+        assert_eq!(facade, inlined);
+        assert_eq!(inlined, facade);
+        assert_ne!(ShortStr::from(FACADE), inlined);
+        assert_ne!(inlined, ShortStr::from(FACADE));
+    }
+
+    #[test]
+    fn empty_eq() {
+        let empty = ShortStr::EMPTY;
+        // facades may never be empty, so this is inlined anyway
+        let unchecked = unsafe { ShortStr::from_str_unchecked("") };
+        assert!(!unchecked.is_str());
+        assert_eq!(empty, ShortStr::from(""));
+        assert_eq!(empty, unchecked);
+        assert_eq!(unchecked, empty);
+        assert_ne!(empty, ShortStr::from(INLINED));
+        assert_ne!(ShortStr::from(INLINED), empty);
+        assert_ne!(empty, ShortStr::from(FACADE));
+        assert_ne!(ShortStr::from(FACADE), empty);
+    }
+
+    #[test]
+    fn str_eq() {
+        let a: [u8; FACADE.len()] = FACADE.as_bytes().try_into().unwrap();
+        let a = core::str::from_utf8(&a).unwrap();
+        assert_eq!(ShortStr::from(FACADE), a);
+        assert_eq!(a, ShortStr::from(FACADE));
+        assert_eq!(ShortStr::from(INLINED), INLINED);
+        assert_eq!(ShortStr::EMPTY, "");
+        assert_ne!(ShortStr::from(INLINED), a);
+        assert_ne!(ShortStr::EMPTY, INLINED);
+    }
+}
+
+/// These are meant to be run with `cargo +nightly miri test` as well, moving and dropping values
+/// while their [`&str`]s are in use is exactly what would be caught there.
+mod borrow {
+    use crate::ShortStr;
+
+    use super::FACADE;
+
+    #[test]
+    fn inlined_as_str_borrows_self() {
+        let short = ShortStr::from("hello");
+        let moved = short;
+        let string = moved.as_str();
+        assert_eq!(string, "hello");
+        assert_eq!(&*moved, "hello");
+    }
+
+    #[test]
+    fn empty_as_str_borrows_self() {
+        let short = ShortStr::EMPTY;
+        assert_eq!(short.as_str(), "");
+        assert_eq!(short.try_as_original(), None);
+    }
+
+    #[test]
+    fn inlined_has_no_original() {
+        let short = ShortStr::from("hello");
+        assert_eq!(short.try_as_original(), None);
+    }
+
+    #[test]
+    fn facade_original_outlives_short_str() {
+        let original = {
+            let short = ShortStr::from(FACADE);
+            short.try_as_original()
+        };
+        assert_eq!(original, Some(FACADE));
+        assert_eq!(original.map(str::as_ptr), Some(FACADE.as_ptr()));
+    }
+
+    #[test]
+    fn facade_as_str_points_at_original() {
+        let short = ShortStr::from(FACADE);
+        assert_eq!(short.as_str().as_ptr(), FACADE.as_ptr());
+    }
+}
+
+mod ordering {
+    extern crate alloc;
+
+    use alloc::{collections::BTreeMap, vec::Vec};
+    use core::cmp::Ordering;
+
+    use crate::ShortStr;
+
+    const STRINGS: &[&str] = &[
+        "",
+        "\0",
+        "\0\0",
+        "a",
+        "a\0",
+        "ab",
+        "abc",
+        "b",
+        "ba",
+        "\u{ff}",
+        "🈁",
+        "fifteen bytes!!",
+        "sixteen bytes!!!",
+        "a string that is too long to be inlined",
+        "a string that is too long to be inlined, and then some",
+        "b string that is too long to be inlined",
+    ];
+
+    #[test]
+    fn matches_str_ordering() {
+        for &a in STRINGS {
+            for &b in STRINGS {
+                let (short_a, short_b) = (ShortStr::from(a), ShortStr::from(b));
+                assert_eq!(short_a.cmp(&short_b), a.cmp(b), "{a:?} vs. {b:?}");
+                assert_eq!(short_a.partial_cmp(&b), Some(a.cmp(b)), "{a:?} vs. {b:?}");
+                assert_eq!(a.partial_cmp(&short_b), Some(a.cmp(b)), "{a:?} vs. {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn facade_ordering_ignores_pointers() {
+        let facade = unsafe { ShortStr::from_str_unchecked("abc") };
+        assert_eq!(facade.cmp(&ShortStr::from("abc")), Ordering::Equal);
+        assert_eq!(facade.cmp(&ShortStr::from("abd")), Ordering::Less);
+        assert_eq!(ShortStr::from("ab").cmp(&facade), Ordering::Less);
+    }
+
+    #[test]
+    fn sorts_like_str() {
+        let mut strings = Vec::from(STRINGS);
+        let mut shorts: Vec<ShortStr> = STRINGS.iter().rev().map(|&s| ShortStr::from(s)).collect();
+        strings.sort();
+        shorts.sort();
+        assert_eq!(shorts, strings);
+    }
+
+    #[test]
+    fn btree_map_keys() {
+        let map: BTreeMap<ShortStr, usize> = STRINGS
+            .iter()
+            .enumerate()
+            .map(|(index, &s)| (ShortStr::from(s), index))
+            .collect();
+        assert_eq!(map.len(), STRINGS.len());
+        for (index, &s) in STRINGS.iter().enumerate() {
+            assert_eq!(map.get(&ShortStr::from(s)), Some(&index));
+        }
+    }
+}
+
+mod hashing {
+    extern crate std;
+
+    use core::hash::{BuildHasher, Hash};
+    use std::collections::{HashMap, HashSet, hash_map::RandomState};
+
+    use crate::{ScalarHash, ShortStr};
+
+    use super::FACADE;
+
+    const STRINGS: &[&str] = &["", "\0", "a", "hello", "fifteen bytes!!", FACADE];
+
+    fn hash_of<T: Hash + ?Sized>(state: &RandomState, value: &T) -> u64 {
+        state.hash_one(value)
+    }
+
+    #[test]
+    fn hash_matches_str() {
+        let state = RandomState::new();
+        for &s in STRINGS {
+            assert_eq!(hash_of(&state, &ShortStr::from(s)), hash_of(&state, s), "{s:?}");
+        }
+        let facade = unsafe { ShortStr::from_str_unchecked("hello") };
+        assert_eq!(hash_of(&state, &facade), hash_of(&state, "hello"));
+    }
+
+    #[test]
+    fn hash_map_lookup_by_str() {
+        let map: HashMap<ShortStr, usize> = STRINGS
+            .iter()
+            .enumerate()
+            .map(|(index, &s)| (ShortStr::from(s), index))
+            .collect();
+        for (index, &s) in STRINGS.iter().enumerate() {
+            assert_eq!(map.get(s), Some(&index), "{s:?}");
+        }
+        assert_eq!(map.get("missing"), None);
+    }
+
+    #[test]
+    fn scalar_hash_consistent_with_eq() {
+        let state = RandomState::new();
+        let facade = ScalarHash(unsafe { ShortStr::from_str_unchecked("hello") });
+        let inlined = ScalarHash(ShortStr::from("hello"));
+        assert_eq!(facade, inlined);
+        assert_eq!(hash_of(&state, &facade), hash_of(&state, &inlined));
+
+        let copy: [u8; FACADE.len()] = FACADE.as_bytes().try_into().unwrap();
+        let copy = core::str::from_utf8(&copy).unwrap();
+        let (a, b) = (ScalarHash(ShortStr::from(FACADE)), ScalarHash(ShortStr::from(copy)));
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&state, &a), hash_of(&state, &b));
+    }
+
+    #[test]
+    fn scalar_hash_set() {
+        let set: HashSet<ScalarHash> = STRINGS.iter().map(|&s| ShortStr::from(s).into()).collect();
+        assert_eq!(set.len(), STRINGS.len());
+        for &s in STRINGS {
+            assert!(set.contains(&ScalarHash(ShortStr::from(s))), "{s:?}");
+        }
+    }
+}
+
+#[test]
+fn inline_str_every_slice_matches_str() {
+    // covers the shift directions of both endians on the full inline capacity
+    let bytes: [u8; INLINE_BYTE_SIZE] = core::array::from_fn(|index| b'a' + index as u8);
+    let string = core::str::from_utf8(&bytes).unwrap();
+    let short = ShStr::from(string);
+    assert!(!short.is_str());
+    for start in 0..=string.len() {
+        for end in start..=string.len() {
+            let slice = short.slice(start..end);
+            str_assert_eq!(
+                slice.as_str(),
+                &string[start..end],
+                slice,
+                "expected slice {}..{} on ShortStr to match &str",
+                start,
+                end
+            );
+            let fresh = ShStr::from(&string[start..end]);
+            str_assert_eq!(
+                slice,
+                fresh,
+                slice,
+                fresh,
+                "expected slice {}..{} on ShortStr to equal a fresh ShortStr",
+                start,
+                end
+            );
+        }
+    }
+}
+
+#[test]
+fn const_construction() {
+    const INLINED: ShortStr<'static> = ShortStr::from_str("hi");
+    const FACADE: ShortStr<'static> = ShortStr::from_str("1 2 3 4 5 6 7 8 9 10");
+    const FACADE_LEN: usize = FACADE.len();
+    assert!(!INLINED.is_str());
+    assert!(FACADE.is_str());
+    assert_eq!(INLINED, "hi");
+    assert_eq!(FACADE, "1 2 3 4 5 6 7 8 9 10");
+    assert_eq!(FACADE_LEN, 20);
+}
+
+#[cfg(feature = "alloc")]
+mod owned {
+    extern crate alloc;
+
+    use alloc::{boxed::Box, string::String};
+
+    use crate::{ShortStr, ShortString};
+
+    use super::FACADE;
+
+    #[test]
+    fn short_content_is_inlined() {
+        let string = ShortString::from(String::from("hello"));
+        assert!(!string.is_heap());
+        assert_eq!(string, "hello");
+        assert_eq!(string.as_short_str(), ShortStr::from("hello"));
+    }
+
+    #[test]
+    fn long_content_is_allocated() {
+        let source = String::from(FACADE);
+        let string = ShortString::from(source.as_str());
+        drop(source);
+        assert!(string.is_heap());
+        assert_eq!(string, FACADE);
+        assert_eq!(string.len(), FACADE.len());
+    }
+
+    #[test]
+    fn reuses_boxed_allocation() {
+        let boxed: Box<str> = Box::from(FACADE);
+        let ptr = boxed.as_ptr();
+        let string = ShortString::from(boxed);
+        assert_eq!(string.as_ptr(), ptr);
+        assert_eq!(string.as_short_str().try_as_original().map(str::as_ptr), Some(ptr));
+    }
+
+    #[test]
+    fn from_short_str() {
+        let inlined = ShortString::from(ShortStr::from("hello"));
+        let allocated = ShortString::from(ShortStr::from(FACADE));
+        assert!(!inlined.is_heap());
+        assert!(allocated.is_heap());
+        assert_ne!(allocated.as_ptr(), FACADE.as_ptr());
+        assert_eq!(inlined, ShortStr::from("hello"));
+        assert_eq!(ShortStr::from(FACADE), allocated);
+    }
+
+    #[test]
+    fn clone_is_deep() {
+        let string = ShortString::from(FACADE);
+        let clone = string.clone();
+        assert_ne!(string.as_ptr(), clone.as_ptr());
+        drop(string);
+        assert_eq!(clone, FACADE);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(ShortString::from(""), ShortString::EMPTY);
+        assert_eq!(ShortString::default(), "");
+        assert!(ShortString::from(String::new()).is_empty());
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod shared {
+    extern crate alloc;
+    extern crate std;
+
+    use alloc::{string::String, sync::Arc};
+
+    use crate::{ArcShortStr, ShortStr};
+
+    use super::FACADE;
+
+    const fn assert_send_sync<T: Send + Sync>() {}
+    const _: () = assert_send_sync::<ArcShortStr>();
+
+    #[test]
+    fn same_size_as_str() {
+        assert_eq!(size_of::<ArcShortStr>(), size_of::<&str>());
+        assert_eq!(size_of::<Option<ArcShortStr>>(), size_of::<&str>());
+    }
+
+    #[test]
+    fn short_content_is_inlined() {
+        let string = ArcShortStr::from(String::from("hello").as_str());
+        assert!(!string.is_shared());
+        assert_eq!(string, "hello");
+        assert_eq!(string.as_short_str(), ShortStr::from("hello"));
+    }
+
+    #[test]
+    fn clone_bumps_count() {
+        let arc: Arc<str> = Arc::from(FACADE);
+        let string = ArcShortStr::from(arc.clone());
+        assert!(string.is_shared());
+        assert_eq!(Arc::strong_count(&arc), 2);
+        let clone = string.clone();
+        assert_eq!(Arc::strong_count(&arc), 3);
+        assert_eq!(clone.as_ptr(), arc.as_ptr());
+        drop(string);
+        assert_eq!(Arc::strong_count(&arc), 2);
+        drop(clone);
+        assert_eq!(Arc::strong_count(&arc), 1);
+    }
+
+    #[test]
+    fn inlined_arc_is_released() {
+        let arc: Arc<str> = Arc::from("hello");
+        let string = ArcShortStr::from(arc.clone());
+        assert!(!string.is_shared());
+        assert_eq!(Arc::strong_count(&arc), 1);
+        assert_eq!(string, "hello");
+    }
+
+    #[test]
+    fn shared_across_threads() {
+        let string = ArcShortStr::from(FACADE);
+        let handles: [_; 4] = core::array::from_fn(|_| {
+            let string = string.clone();
+            std::thread::spawn(move || string.len())
+        });
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), FACADE.len());
+        }
+        assert_eq!(string, FACADE);
+    }
+}
+
+mod indexing {
+    use crate::ShortStr;
+
+    use super::{inside_last_char, MULTIBYTE_FACADE as FACADE, MULTIBYTE_INLINED as INLINED};
+
+    #[test]
+    fn index_matches_str() {
+        for string in [INLINED, FACADE] {
+            let short = ShortStr::from(string);
+            let boundaries = (0..=string.len()).filter(|&index| string.is_char_boundary(index));
+            for start in boundaries.clone() {
+                for end in boundaries.clone().filter(|&end| end > start) {
+                    assert_eq!(&short[start..end], &string[start..end]);
+                    assert_eq!(&short[start..], &string[start..]);
+                    assert_eq!(&short[..end], &string[..end]);
+                    assert_eq!(&short[start..=end - 1], &string[start..=end - 1]);
+                    assert_eq!(&short[..=end - 1], &string[..=end - 1]);
+                }
+            }
+            assert_eq!(&short[..], string);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_inlined_splitting_grapheme_panics() {
+        let short = ShortStr::from(INLINED);
+        let _ = &short[..inside_last_char(INLINED)];
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_facade_splitting_grapheme_panics() {
+        let short = ShortStr::from(FACADE);
+        let _ = &short[..inside_last_char(FACADE)];
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds_panics() {
+        let short = ShortStr::from(INLINED);
+        let _ = &short[..INLINED.len() + 1];
+    }
+
+    #[test]
+    fn get_matches_str_get() {
+        for string in [INLINED, FACADE] {
+            let short = ShortStr::from(string);
+            for start in 0..=string.len() + 1 {
+                for end in 0..=string.len() + 1 {
+                    assert_eq!(
+                        short.get(start..end).map(|s| s.len()),
+                        string.get(start..end).map(str::len),
+                        "{string:?}[{start}..{end}]"
+                    );
+                    if let Some(slice) = short.get(start..end) {
+                        assert_eq!(slice, string.get(start..end).unwrap());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn get_keeps_original_lifetime() {
+        let string = FACADE;
+        let slice = {
+            let short = ShortStr::from(string);
+            short.get(2..).unwrap()
+        };
+        assert_eq!(slice.try_as_original(), Some(&string[2..]));
+    }
+
+    #[test]
+    fn get_unchecked_matches_str() {
+        let short = ShortStr::from(INLINED);
+        assert_eq!(unsafe { short.get_unchecked(..2) }, &INLINED[..2]);
+        assert_eq!(unsafe { short.get_unchecked(2..) }, &INLINED[2..]);
+    }
+}
+
+mod char_boundaries {
+    extern crate std;
+
+    use std::panic::catch_unwind;
+
+    use crate::{ShortStr, SliceError};
+
+    use super::{inside_last_char, MULTIBYTE_FACADE as FACADE, MULTIBYTE_INLINED as INLINED};
+
+    #[test]
+    fn is_char_boundary_matches_str() {
+        for string in [INLINED, FACADE, ""] {
+            let short = ShortStr::from(string);
+            for index in 0..=string.len() + 1 {
+                assert_eq!(
+                    short.is_char_boundary(index),
+                    string.is_char_boundary(index),
+                    "{string:?} at {index}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn try_slice_errors() {
+        for string in [INLINED, FACADE] {
+            let short = ShortStr::from(string);
+            let len = string.len();
+            assert_eq!(
+                short.try_slice(..len + 1),
+                Err(SliceError::OutOfBounds { index: len + 1, len })
+            );
+            assert_eq!(
+                short.try_slice(len + 2..len + 1),
+                Err(SliceError::OutOfBounds { index: len + 2, len })
+            );
+            assert_eq!(
+                short.try_slice(3..2),
+                Err(SliceError::Descending { start: 3, end: 2 })
+            );
+        }
+        let inlined = ShortStr::from(INLINED);
+        assert_eq!(
+            inlined.try_slice(1..),
+            Err(SliceError::NotCharBoundary { index: 1 })
+        );
+        assert_eq!(
+            inlined.try_slice(..3),
+            Err(SliceError::NotCharBoundary { index: 3 })
+        );
+        assert_eq!(inlined.try_slice(2..4), Ok(ShortStr::from("é")));
+        let facade = ShortStr::from(FACADE);
+        let index = inside_last_char(FACADE);
+        assert_eq!(facade.try_slice(index..), Err(SliceError::NotCharBoundary { index }));
+    }
+
+    #[test]
+    fn slice_panics_like_str() {
+        for string in [INLINED, FACADE] {
+            let splits = inside_last_char(string);
+            let cases = [(1, splits), (splits, splits + 2), (splits + 1, splits), (0, string.len() + 1)];
+            for (start, end) in cases {
+                let expected = catch_unwind(|| {
+                    let _ = &string[start..end];
+                });
+                let actual = catch_unwind(|| {
+                    let _ = ShortStr::from(string).slice(start..end);
+                });
+                let (Err(expected), Err(actual)) = (expected, actual) else {
+                    panic!("expected both slicing {string:?} with {start}..{end} to panic");
+                };
+                assert_eq!(
+                    expected.downcast_ref::<std::string::String>(),
+                    actual.downcast_ref::<std::string::String>()
+                );
+            }
+        }
+    }
+}
+
+mod bounds {
+    use core::ops::Bound;
+
+    use crate::{ShortStr, SliceError};
+
+    use super::{MULTIBYTE_FACADE as FACADE, MULTIBYTE_INLINED as INLINED};
+
+    /// Every bound over the interesting indices of `string`, including the overflowing ones
+    fn arbitrary_bounds(string: &str) -> impl Iterator<Item = Bound<usize>> + Clone {
+        let indices = (0..=string.len() + 1).chain([usize::MAX - 1, usize::MAX]);
+        let included = indices.clone().map(Bound::Included);
+        let excluded = indices.map(Bound::Excluded);
+        included.chain(excluded).chain([Bound::Unbounded])
+    }
+
+    #[test]
+    fn excluded_start_into_range() {
+        let short = ShortStr::from("abc");
+        assert_eq!(short.bounds_to_range((Bound::Excluded(0), Bound::Unbounded)), Ok(1..3));
+        assert_eq!(
+            short.bounds_to_range((Bound::Excluded(0), Bound::Included(1))),
+            Ok(1..2)
+        );
+    }
+
+    #[test]
+    fn overflowing_bounds() {
+        let short = ShortStr::from("abc");
+        assert_eq!(short.bounds_to_range(..=usize::MAX), Err(SliceError::Overflow));
+        assert_eq!(
+            short.bounds_to_range((Bound::Excluded(usize::MAX), Bound::Unbounded)),
+            Err(SliceError::Overflow)
+        );
+        assert_eq!(short.try_slice(1..=usize::MAX), Err(SliceError::Overflow));
+        assert_eq!(short.get(..=usize::MAX), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn overflowing_slice_panics() {
+        let _ = ShortStr::from("abc").slice(..=usize::MAX);
+    }
+
+    #[test]
+    fn get_matches_str_get_for_arbitrary_bounds() {
+        for string in [INLINED, FACADE, ""] {
+            let short = ShortStr::from(string);
+            for start in arbitrary_bounds(string) {
+                for end in arbitrary_bounds(string) {
+                    let bounds = (start, end);
+                    let expected = string.get(bounds);
+                    let actual = short.get(bounds);
+                    assert_eq!(
+                        actual.map(|s| s.len()),
+                        expected.map(str::len),
+                        "{string:?}[{bounds:?}]"
+                    );
+                    if let (Some(actual), Some(expected)) = (actual, expected) {
+                        assert_eq!(actual, expected, "{string:?}[{bounds:?}]");
+                        assert_eq!(&short[bounds], expected, "{string:?}[{bounds:?}]");
+                    }
+                    assert_eq!(
+                        short.try_slice(bounds).is_ok(),
+                        expected.is_some(),
+                        "{string:?}[{bounds:?}]"
+                    );
+                }
+            }
+        }
+    }
+}
+
+mod const_slicing {
+    extern crate std;
+
+    use std::panic::catch_unwind;
+
+    use crate::{ShortStr, SliceError};
+
+    use super::{inside_last_char, MULTIBYTE_FACADE as FACADE, MULTIBYTE_INLINED as INLINED};
+
+    #[test]
+    fn const_items() {
+        const KEYWORD: ShortStr<'static> = ShortStr::from_str("function");
+        const PREFIXES: [ShortStr<'static>; 3] = [
+            KEYWORD.slice_range(0, 1),
+            KEYWORD.slice_range(0, 4),
+            KEYWORD.slice_range(4, 8),
+        ];
+        const LONG: ShortStr<'static> = ShortStr::from_str(FACADE);
+        const LONG_PREFIX: ShortStr<'static> = LONG.slice_range(0, 30);
+        const LONG_WORD: ShortStr<'static> = LONG.slice_range(0, 4);
+        const ERROR: Result<ShortStr<'static>, SliceError> = LONG.try_slice_range(0, 1);
+        assert_eq!(PREFIXES, ["f", "func", "tion"]);
+        assert!(LONG_PREFIX.is_str());
+        assert_eq!(LONG_PREFIX, &FACADE[..30]);
+        assert!(!LONG_WORD.is_str());
+        assert_eq!(LONG_WORD, "éé");
+        assert_eq!(ERROR, Err(SliceError::NotCharBoundary { index: 1 }));
+    }
+
+    #[test]
+    fn matches_try_slice() {
+        for string in [INLINED, FACADE, ""] {
+            let short = ShortStr::from(string);
+            for start in 0..=string.len() + 1 {
+                for end in 0..=string.len() + 1 {
+                    assert_eq!(
+                        short.try_slice_range(start, end),
+                        short.try_slice(start..end),
+                        "{string:?}[{start}..{end}]"
+                    );
+                    if let Ok(slice) = short.try_slice(start..end) {
+                        assert_eq!(short.slice_range(start, end), slice);
+                        assert_eq!(unsafe { short.slice_range_unchecked(start, end) }, slice);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn slice_range_panics_with_error_message() {
+        let cases = [
+            (INLINED, 0, 1),
+            (FACADE, 0, inside_last_char(FACADE)),
+            (INLINED, 3, 2),
+            (FACADE, 0, FACADE.len() + 1),
+        ];
+        for (string, start, end) in cases {
+            let short = ShortStr::from(string);
+            let error = short.try_slice_range(start, end).unwrap_err();
+            let panic = catch_unwind(|| short.slice_range(start, end)).unwrap_err();
+            let message = panic
+                .downcast_ref::<std::string::String>()
+                .map(std::string::String::as_str)
+                .or_else(|| panic.downcast_ref::<&str>().copied());
+            assert_eq!(message, Some(std::format!("{error}").as_str()));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_range_unchecked_facade_splitting_grapheme_panics() {
+        let _ = unsafe { ShortStr::from(FACADE).slice_range_unchecked(0, inside_last_char(FACADE)) };
+    }
+}
+
+mod macros {
+    use crate::{inline_str, short_str, ShortStr, INLINE_BYTE_SIZE};
+
+    use super::{FACADE, INLINED};
+
+    #[test]
+    fn short_str_in_const_items() {
+        const INLINED: ShortStr<'static> = short_str!("hello");
+        const LONG: ShortStr<'static> = short_str!(FACADE);
+        const EMPTY: ShortStr<'static> = short_str!("");
+        assert!(!INLINED.is_str());
+        assert_eq!(INLINED, ShortStr::from("hello"));
+        assert!(LONG.is_str());
+        assert_eq!(LONG.try_as_original(), Some(FACADE));
+        assert_eq!(EMPTY, ShortStr::EMPTY);
+    }
+
+    #[test]
+    fn inline_str_up_to_capacity() {
+        const FULL: ShortStr<'static> = inline_str!(INLINED);
+        assert!(!FULL.is_str());
+        assert_eq!(FULL.len(), INLINE_BYTE_SIZE);
+        assert_eq!(FULL, INLINED);
+        assert!(!inline_str!("hi").is_str());
+    }
+}
+
+mod inline {
+    extern crate std;
+
+    use std::{collections::HashSet, format, string::String};
+
+    use crate::{CapacityError, InlineStr, ShortStr, INLINE_BYTE_SIZE};
+
+    use super::{FACADE, INLINED};
+
+    #[test]
+    fn same_size_as_str() {
+        assert_eq!(size_of::<InlineStr>(), size_of::<&str>());
+        assert_eq!(size_of::<Option<InlineStr>>(), size_of::<&str>());
+    }
+
+    #[test]
+    fn fits_up_to_capacity() {
+        let inlined = InlineStr::try_from(INLINED).unwrap();
+        assert_eq!(inlined, INLINED);
+        assert_eq!(InlineStr::try_from(""), Ok(InlineStr::EMPTY));
+        assert_eq!(
+            InlineStr::try_from(&FACADE[..INLINE_BYTE_SIZE + 1]),
+            Err(CapacityError { len: INLINE_BYTE_SIZE + 1 })
+        );
+    }
+
+    #[test]
+    fn owns_its_bytes() {
+        let inlined = {
+            let string = String::from("hello");
+            InlineStr::try_from(string.as_str()).unwrap()
+        };
+        assert_eq!(inlined, "hello");
+    }
+
+    #[test]
+    fn converts_into_any_short_str() {
+        let inlined = InlineStr::try_from("hello").unwrap();
+        let string = String::from("hello");
+        let short: ShortStr<'_> = inlined.into();
+        assert!(!short.is_str());
+        assert_eq!(short, ShortStr::from(string.as_str()));
+        let unchecked = unsafe { ShortStr::from_str_unchecked(string.as_str()) };
+        assert_eq!(InlineStr::try_from(unchecked), Ok(inlined));
+        assert_eq!(
+            InlineStr::try_from(ShortStr::from(FACADE)),
+            Err(CapacityError { len: FACADE.len() })
+        );
+    }
+
+    #[test]
+    fn capacity_error_message() {
+        let error = InlineStr::try_from(FACADE).unwrap_err();
+        assert_eq!(
+            format!("{error}"),
+            format!(
+                "string of length {} exceeds the inline capacity of {INLINE_BYTE_SIZE} bytes",
+                FACADE.len()
+            )
+        );
+    }
+
+    #[test]
+    fn hash_set_lookup_by_str() {
+        let set: HashSet<InlineStr> = ["a", "b", "hello"]
+            .into_iter()
+            .map(|string| InlineStr::try_from(string).unwrap())
+            .collect();
+        assert!(set.contains("hello"));
+        assert!(!set.contains("c"));
+    }
+}
+
+mod formatting {
+    extern crate std;
+
+    use core::fmt::{Display, Formatter, Write};
+    use std::panic::catch_unwind;
+
+    use crate::{short_format, CapacityError, InlineBuilder, ShortStr, INLINE_BYTE_SIZE};
+
+    #[test]
+    fn builder_matches_from_str() {
+        let mut builder = InlineBuilder::new();
+        let (namespace, id) = ("ns", 1);
+        write!(builder, "{namespace}:{id}").unwrap();
+        let short = ShortStr::from(builder.finish().unwrap());
+        assert!(!short.is_str());
+        assert_eq!(short.to_bytes(), ShortStr::from("ns:1").to_bytes());
+        assert_eq!(InlineBuilder::new().finish().unwrap(), ShortStr::EMPTY);
+    }
+
+    #[test]
+    fn builder_up_to_capacity() {
+        let mut builder = InlineBuilder::new();
+        for _ in 0..INLINE_BYTE_SIZE {
+            builder.push_str("x").unwrap();
+        }
+        assert_eq!(builder.push_str(""), Ok(()));
+        assert_eq!(builder.len(), INLINE_BYTE_SIZE);
+        assert_eq!(
+            builder.push_str("yz"),
+            Err(CapacityError { len: INLINE_BYTE_SIZE + 2 })
+        );
+        assert_eq!(builder.len(), INLINE_BYTE_SIZE);
+        assert!(builder.is_overflowed());
+        assert_eq!(builder.finish(), Err(CapacityError { len: INLINE_BYTE_SIZE + 2 }));
+    }
+
+    #[test]
+    fn builder_stays_overflowed() {
+        let mut builder = InlineBuilder::new();
+        let long = "x".repeat(INLINE_BYTE_SIZE + 1);
+        assert!(builder.push_str(&long).is_err());
+        assert!(builder.push_str("a").is_err());
+        assert_eq!(builder.as_str(), "");
+    }
+
+    #[test]
+    fn short_format_matches_format() {
+        let (prefix, id) = ("k", 12);
+        let key = short_format!("{prefix}{id}").unwrap();
+        assert_eq!(key, std::format!("{prefix}{id}").as_str());
+        assert_eq!(short_format!(""), Ok(ShortStr::EMPTY));
+        let too_long = std::format!("{prefix}{:0width$}", id, width = INLINE_BYTE_SIZE);
+        assert_eq!(
+            short_format!("{prefix}{:0width$}", id, width = INLINE_BYTE_SIZE),
+            Err(CapacityError { len: too_long.len() })
+        );
+    }
+
+    #[test]
+    fn short_format_panics_on_failing_impl() {
+        struct Failing;
+
+        impl Display for Failing {
+            fn fmt(&self, _: &mut Formatter<'_>) -> core::fmt::Result {
+                Err(core::fmt::Error)
+            }
+        }
+
+        assert!(catch_unwind(|| short_format!("{}", Failing)).is_err());
+    }
+}
+
+mod concatenation {
+    use crate::ShortStr;
+
+    use super::{FACADE, INLINED};
+
+    #[test]
+    fn try_concat_every_split_matches_from_str() {
+        // covers the shift directions of both endians on the full inline capacity
+        let string = INLINED;
+        for end in 0..=string.len() {
+            for split in 0..=end {
+                let (left, right) = (&string[..split], &string[split..end]);
+                let concat = ShortStr::from(left).try_concat(ShortStr::from(right)).unwrap();
+                let expected = ShortStr::from(&string[..end]);
+                assert_eq!(concat.to_bytes(), expected.to_bytes(), "{left:?} + {right:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn try_concat_unchecked_facades() {
+        let left = unsafe { ShortStr::from_str_unchecked("ab") };
+        let right = unsafe { ShortStr::from_str_unchecked("cd") };
+        let concat = left.try_concat(right).unwrap();
+        assert!(!concat.is_str());
+        assert_eq!(concat.to_bytes(), ShortStr::from("abcd").to_bytes());
+    }
+
+    #[test]
+    fn try_concat_too_long() {
+        assert_eq!(ShortStr::from(INLINED).try_concat(ShortStr::from("x")), None);
+        assert_eq!(ShortStr::from(FACADE).try_concat(ShortStr::EMPTY), None);
+    }
+
+    #[test]
+    fn try_concat_outlives_inputs() {
+        extern crate std;
+
+        let concat = {
+            let (left, right) = (std::string::String::from("ab"), std::string::String::from("é"));
+            ShortStr::from(left.as_str()).try_concat(ShortStr::from(right.as_str()))
+        };
+        assert_eq!(concat, Some(ShortStr::from("abé")));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn concat_falls_back_to_owned() {
+        let inlined = ShortStr::from("ab").concat(ShortStr::from("cd"));
+        assert!(!inlined.is_heap());
+        assert_eq!(inlined, "abcd");
+        let owned = ShortStr::from(FACADE).concat(ShortStr::from("!"));
+        assert!(owned.is_heap());
+        assert_eq!(owned.as_str(), [FACADE, "!"].concat());
+    }
+}
+
+mod splitting {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use crate::ShortStr;
+
+    const STRINGS: [&str; 9] = [
+        "",
+        ",",
+        "a,b,,c,",
+        "é,🈁",
+        "a\r\nb\n\nc\n",
+        "\r\n\n\r",
+        " fn  main()\t{ }\n",
+        "a string, that is too long, to be inlined",
+        "a string\r\nthat is too long\n\n to be\tinlined \r",
+    ];
+
+    fn assert_pieces<'a>(
+        actual: impl Iterator<Item = ShortStr<'a>>,
+        expected: impl Iterator<Item = &'a str>,
+        string: &str,
+    ) {
+        assert_eq!(actual.collect::<Vec<_>>(), expected.collect::<Vec<_>>(), "{string:?}");
+    }
+
+    #[test]
+    fn split_matches_str() {
+        for string in STRINGS {
+            let short = ShortStr::from(string);
+            assert_pieces(short.split(','), string.split(','), string);
+            assert_pieces(short.split(", "), string.split(", "), string);
+            assert_pieces(short.split(""), string.split(""), string);
+            assert_pieces(short.split(['a', '🈁']), string.split(['a', '🈁']), string);
+            assert_pieces(
+                short.split(char::is_whitespace),
+                string.split(char::is_whitespace),
+                string,
+            );
+        }
+    }
+
+    #[test]
+    fn split_once_matches_str() {
+        for string in STRINGS {
+            let short = ShortStr::from(string);
+            for pattern in [",", "\n", "", "🈁", "to"] {
+                let expected = string.split_once(pattern);
+                let actual = short.split_once(pattern);
+                assert_eq!(actual, expected.map(|(a, b)| (ShortStr::from(a), ShortStr::from(b))));
+                let expected = string.rsplit_once(pattern);
+                let actual = short.rsplit_once(pattern);
+                assert_eq!(actual, expected.map(|(a, b)| (ShortStr::from(a), ShortStr::from(b))));
+            }
+        }
+    }
+
+    #[test]
+    fn lines_and_whitespace_match_str() {
+        for string in STRINGS {
+            let short = ShortStr::from(string);
+            assert_pieces(short.lines(), string.lines(), string);
+            assert_pieces(short.split_whitespace(), string.split_whitespace(), string);
+        }
+    }
+
+    #[test]
+    fn pieces_outlive_inlined_source() {
+        let string = "k=value";
+        let (key, value) = {
+            let temporary = ShortStr::from(string);
+            assert!(!temporary.is_str());
+            temporary.split_once('=').unwrap()
+        };
+        assert_eq!((key, value), (ShortStr::from("k"), ShortStr::from("value")));
+    }
+
+    #[test]
+    fn facade_pieces_point_into_original() {
+        let string = "a string that is too long,inlined";
+        let (long, short) = ShortStr::from(string).split_once(',').unwrap();
+        assert!(long.is_str());
+        assert_eq!(long.try_as_original().map(str::as_ptr), Some(string.as_ptr()));
+        assert!(!short.is_str());
+        assert_eq!(short, "inlined");
+    }
+}
+
+mod trimming {
+    use crate::ShortStr;
+
+    const STRINGS: [&str; 8] = [
+        "",
+        "  ",
+        " \thi\n",
+        "--key--",
+        "ababkeyab",
+        "🈁é🈁",
+        "  a string that is too long to be inlined\r\n",
+        "--a string that is too long to be inlined--",
+    ];
+
+    #[test]
+    fn trim_matches_str() {
+        for string in STRINGS {
+            let short = ShortStr::from(string);
+            assert_eq!(short.trim(), string.trim(), "{string:?}");
+            assert_eq!(short.trim_start(), string.trim_start(), "{string:?}");
+            assert_eq!(short.trim_end(), string.trim_end(), "{string:?}");
+            assert_eq!(short.trim_matches('-'), string.trim_matches('-'), "{string:?}");
+            assert_eq!(short.trim_matches(['🈁', 'b']), string.trim_matches(['🈁', 'b']));
+            assert_eq!(short.trim_start_matches("ab"), string.trim_start_matches("ab"));
+            assert_eq!(short.trim_end_matches("ab"), string.trim_end_matches("ab"));
+            assert_eq!(short.trim_start_matches(""), string.trim_start_matches(""));
+        }
+    }
+
+    #[test]
+    fn strip_matches_str() {
+        for string in STRINGS {
+            let short = ShortStr::from(string);
+            for pattern in ["", "-", "--", "ab", "🈁", "\n"] {
+                assert_eq!(
+                    short.strip_prefix(pattern),
+                    string.strip_prefix(pattern).map(ShortStr::from),
+                    "{string:?}"
+                );
+                assert_eq!(
+                    short.strip_suffix(pattern),
+                    string.strip_suffix(pattern).map(ShortStr::from),
+                    "{string:?}"
+                );
+            }
+            assert_eq!(
+                short.strip_prefix(char::is_whitespace),
+                string.strip_prefix(char::is_whitespace).map(ShortStr::from)
+            );
+        }
+    }
+
+    #[test]
+    fn trimmed_outlives_inlined_source() {
+        let string = " hi ";
+        let trimmed = {
+            let temporary = ShortStr::from(string);
+            assert!(!temporary.is_str());
+            temporary.trim()
+        };
+        assert_eq!(trimmed, "hi");
+    }
+
+    #[test]
+    fn trimmed_facade_points_into_original() {
+        let string = STRINGS[6];
+        let trimmed = ShortStr::from(string).trim();
+        assert!(trimmed.is_str());
+        assert_eq!(trimmed.try_as_original(), Some(string.trim()));
+        assert_eq!(trimmed.try_as_original().map(str::as_ptr), Some(string[2..].as_ptr()));
+    }
+}
+
+mod normalization {
+    extern crate std;
+
+    use std::hash::{BuildHasher, RandomState};
+
+    use crate::{ScalarHash, ShortStr, MARKER_INDEX};
+
+    use super::INLINED;
+
+    #[test]
+    fn nested_slices_normalize_to_from_str() {
+        let string = INLINED;
+        let short = ShortStr::from(string);
+        for start in 0..=string.len() {
+            for end in start..=string.len() {
+                let slice = short.slice(start..end);
+                for inner_start in 0..=slice.len() {
+                    for inner_end in inner_start..=slice.len() {
+                        let inner = slice.slice(inner_start..inner_end);
+                        let expected = &string[start..end][inner_start..inner_end];
+                        let fresh = ShortStr::from(expected);
+                        assert_eq!(inner.as_str(), expected);
+                        assert_eq!(inner, fresh);
+                        assert_eq!(inner.cmp(&fresh), core::cmp::Ordering::Equal);
+                        assert_eq!(inner.normalize().to_bytes(), fresh.to_bytes());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sliced_values_hash_and_concat_like_from_str() {
+        let state = RandomState::new();
+        let short = ShortStr::from("hello");
+        let sliced = short.slice(1..4);
+        let fresh = ShortStr::from("ell");
+        assert_eq!(state.hash_one(sliced), state.hash_one(fresh));
+        assert_eq!(state.hash_one(ScalarHash(sliced)), state.hash_one(ScalarHash(fresh)));
+        assert_eq!(sliced.try_concat(sliced), Some(ShortStr::from("ellell")));
+        assert!(sliced.is_char_boundary(3));
+        assert!(sliced < ShortStr::from("elm"));
+        assert!(sliced > ShortStr::from("el"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "inline-offset", not(feature = "eager-normalization")))]
+    fn lazy_slicing_keeps_bytes() {
+        let short = ShortStr::from("hello");
+        let sliced = short.slice(1..4);
+        assert_eq!(sliced.to_bytes()[..5], *b"hello");
+        assert_eq!(sliced.to_bytes()[MARKER_INDEX] & !crate::INLINE_FLAG, 1 << 4 | 3);
+        assert_eq!(sliced.normalize().to_bytes()[..5], *b"ell\0\0");
+    }
+
+    #[test]
+    #[cfg(any(not(feature = "inline-offset"), feature = "eager-normalization"))]
+    fn eager_slicing_normalizes() {
+        let sliced = ShortStr::from("hello").slice(1..4);
+        assert_eq!(sliced.to_bytes()[..5], *b"ell\0\0");
+        assert_eq!(sliced.to_bytes()[MARKER_INDEX] & !crate::INLINE_FLAG, 3);
+        assert_eq!(sliced.normalize().to_bytes(), sliced.to_bytes());
+        assert_eq!(sliced.slice(1..).to_bytes(), ShortStr::from("ll").to_bytes());
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    extern crate std;
+
+    use serde::Deserialize;
+    use serde_json::Deserializer;
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, Token};
+
+    use crate::{ShortStr, INLINE_BYTE_SIZE};
+
+    use super::{FACADE, INLINED};
+
+    #[test]
+    fn serializes_as_str() {
+        for string in ["", "hello", FACADE] {
+            assert_ser_tokens(&ShortStr::from(string), &[Token::Str(string)]);
+        }
+    }
+
+    #[test]
+    fn borrowed_deserializes_like_from_str() {
+        for string in ["", "hello", FACADE] {
+            assert_de_tokens(&ShortStr::from(string), &[Token::BorrowedStr(string)]);
+        }
+
+        let json = serde_json::to_string(&[FACADE, "hello"]).unwrap();
+        let [facade, inlined]: [ShortStr; 2] = serde_json::from_str(&json).unwrap();
+        assert!(facade.is_str());
+        assert!(json.as_bytes().as_ptr_range().contains(&facade.as_str().as_ptr()));
+        assert!(!inlined.is_str());
+        assert_eq!(inlined, "hello");
+    }
+
+    #[test]
+    fn transient_deserializes_when_inlined() {
+        assert_de_tokens(&ShortStr::from(INLINED), &[Token::String(INLINED)]);
+        assert_de_tokens(&ShortStr::EMPTY, &[Token::String("")]);
+
+        let mut reader = Deserializer::from_reader(&b"\"hello\""[..]);
+        let inlined = ShortStr::deserialize(&mut reader).unwrap();
+        assert_eq!(inlined, "hello");
+        // escapes make serde_json unescape into a transient buffer
+        let escaped: ShortStr = serde_json::from_str(r#""\tab""#).unwrap();
+        assert_eq!(escaped, "\tab");
+    }
+
+    #[test]
+    fn transient_too_long_errors() {
+        let message = std::format!(
+            "string of length {} exceeds the inline capacity of {INLINE_BYTE_SIZE} bytes, and a \
+             ShortStr can only borrow strings that are not transient (e.g. not read from a \
+             stream or unescaped)",
+            FACADE.len()
+        );
+        assert_de_tokens_error::<ShortStr>(&[Token::String(FACADE)], &message);
+        let json = serde_json::to_vec(FACADE).unwrap();
+        let mut reader = Deserializer::from_reader(json.as_slice());
+        assert!(ShortStr::deserialize(&mut reader).is_err());
+    }
+
+    #[test]
+    fn non_strings_error() {
+        assert_de_tokens_error::<ShortStr>(
+            &[Token::U8(1)],
+            &std::format!(
+                "invalid type: integer `1`, expected a borrowed string, or a string of at most \
+                 {INLINE_BYTE_SIZE} bytes"
+            ),
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod interning {
+    extern crate alloc;
+
+    use alloc::{string::String, vec::Vec};
+
+    use crate::{Interner, ShortStr, INLINE_BYTE_SIZE};
+
+    use super::{strings, FACADE};
+
+    #[test]
+    fn interned_matches_source() {
+        let interner = Interner::new();
+        let strings = strings();
+        let interned: Vec<ShortStr> = strings.iter().map(|s| interner.intern(s)).collect();
+        for (string, short) in strings.iter().zip(&interned) {
+            assert_eq!(short.as_str(), string);
+            assert_eq!(short.is_str(), string.len() > INLINE_BYTE_SIZE);
+            assert_ne!(short.try_as_original().map(str::as_ptr), Some(string.as_ptr()));
+        }
+        let stored = strings.iter().filter(|s| s.len() > INLINE_BYTE_SIZE);
+        let mut stored: Vec<&String> = stored.collect();
+        stored.sort();
+        stored.dedup();
+        assert_eq!(interner.len(), stored.len());
+    }
+
+    #[test]
+    fn scalar_eq_matches_eq() {
+        let interner = Interner::new();
+        let strings = strings();
+        let first: Vec<ShortStr> = strings.iter().map(|s| interner.intern(s)).collect();
+        let second: Vec<ShortStr> =
+            strings.iter().map(|s| interner.intern(&s.clone())).collect();
+        for a in &first {
+            for b in &second {
+                assert_eq!(a.scalar_eq(*b), a == b, "{a:?} {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn short_strings_are_not_stored() {
+        let interner = Interner::new();
+        let short = interner.intern("short");
+        assert!(!short.is_str());
+        assert!(interner.is_empty());
+        assert_eq!(interner.get("short"), Some(short));
+        assert_eq!(interner.intern(""), ShortStr::EMPTY);
+        assert!(interner.is_empty());
+    }
+
+    #[test]
+    fn outlives_source() {
+        let interner = Interner::new();
+        let source = String::from(FACADE);
+        let interned = interner.intern(&source);
+        drop(source);
+        assert_eq!(interned, FACADE);
+        assert!(interner.get(FACADE).unwrap().scalar_eq(interned));
+    }
+
+    #[test]
+    fn scalar_eq_compares_facades_by_address() {
+        let copy = String::from(FACADE);
+        assert!(ShortStr::from(FACADE).scalar_eq(ShortStr::from(FACADE)));
+        assert!(!ShortStr::from(FACADE).scalar_eq(ShortStr::from(copy.as_str())));
+        assert!(!ShortStr::from(FACADE).scalar_eq(ShortStr::from(&FACADE[..FACADE.len() - 1])));
+        assert!(ShortStr::from("a str").scalar_eq(ShortStr::from(&FACADE[..5])));
+        assert!(ShortStr::from("ello").scalar_eq(ShortStr::from("hello").slice(1..)));
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod arena {
+    extern crate alloc;
+
+    use alloc::{string::String, vec::Vec};
+
+    use crate::{Arena, ShortStr, INLINE_BYTE_SIZE};
+
+    #[test]
+    fn copies_only_when_not_inlined() {
+        let arena = Arena::new();
+        let strings: Vec<String> = (0..=INLINE_BYTE_SIZE + 1).map(|len| "a".repeat(len)).collect();
+        for string in &strings {
+            let short = ShortStr::from_str_in(string, &arena);
+            assert_eq!(short, ShortStr::from(string.as_str()));
+            assert_eq!(short.is_str(), string.len() > INLINE_BYTE_SIZE);
+            if let Some(original) = short.try_as_original() {
+                assert_ne!(original.as_ptr(), string.as_ptr());
+            }
+        }
+    }
+
+    #[test]
+    fn copies_outlive_sources() {
+        let arena = Arena::new();
+        // fill several chunks, including ones larger than any regular chunk
+        let lengths = (0..2000).map(|i| i % 300).chain([5000, 3_000_000, 1]);
+        let copies: Vec<(String, ShortStr)> = lengths
+            .map(|len| {
+                let string: String = (0..len).map(|i| char::from(b'a' + (i % 26) as u8)).collect();
+                let short = ShortStr::from_str_in(&string.clone(), &arena);
+                (string, short)
+            })
+            .collect();
+        for (string, short) in &copies {
+            assert_eq!(short.as_str(), string);
+        }
+    }
+
+    #[test]
+    fn multibyte_and_empty() {
+        let arena = Arena::new();
+        assert_eq!(ShortStr::from_str_in("", &arena), ShortStr::EMPTY);
+        assert_eq!(arena.alloc_str(""), "");
+        let string = "ŝŝŝŝŝŝŝŝŝŝŝŝŝŝŝŝŝŝŝŝ";
+        assert_eq!(ShortStr::from_str_in(string, &arena), string);
+        assert_eq!(arena.alloc_str("hi"), "hi");
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod packed_vec {
+    extern crate alloc;
+
+    use alloc::{string::String, vec::Vec};
+
+    use crate::{ShortStr, ShortStrVec};
+
+    use super::{strings, FACADE};
+
+    #[test]
+    fn contains_matches_slice_contains() {
+        let strings = strings();
+        let missing = ["missing", "ŝŝ", &FACADE[..FACADE.len() - 1]].map(String::from);
+        // every remainder of the blocks contains compares at once, and all strings
+        for count in (0..=20).chain([strings.len()]) {
+            let vec: ShortStrVec = strings[..count].iter().map(String::as_str).collect();
+            for needle in strings.iter().chain(&missing) {
+                assert_eq!(vec.contains(needle), strings[..count].contains(needle), "{needle:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn contains_sliced_and_unchecked_values() {
+        let owned = String::from(FACADE);
+        let mut vec = ShortStrVec::new();
+        vec.push(ShortStr::from("hello").slice(1..4));
+        vec.push(unsafe { ShortStr::from_str_unchecked(&owned[..3]) });
+        vec.push(ShortStr::from(owned.as_str()));
+        assert!(vec.contains("ell"));
+        assert!(vec.contains("a s"));
+        assert!(vec.contains(FACADE));
+        assert!(!vec.contains("hello"));
+        assert!(!vec.get(1).unwrap().is_str());
+    }
+
+    #[test]
+    fn sort_and_dedup_match_vec() {
+        let strings = strings();
+        let mut vec: ShortStrVec = strings.iter().map(String::as_str).collect();
+        let mut expected: Vec<&str> = strings.iter().map(String::as_str).collect();
+        vec.sort();
+        expected.sort();
+        assert_eq!(vec.iter().collect::<Vec<_>>(), expected);
+        vec.dedup();
+        expected.dedup();
+        assert_eq!(vec.iter().collect::<Vec<_>>(), expected);
+        assert_eq!(vec.len(), expected.len());
+    }
+
+    #[test]
+    fn iteration_keeps_lifetime() {
+        let source = String::from("let x = y");
+        let pieces: Vec<ShortStr> = {
+            let vec: ShortStrVec = source.split(' ').collect();
+            vec.iter().rev().collect()
+        };
+        assert_eq!(pieces, ["y", "=", "x", "let"]);
+        let vec: ShortStrVec = source.split(' ').collect();
+        assert_eq!(vec.iter().len(), 4);
+        assert_eq!((&vec).into_iter().count(), 4);
+        assert_eq!(vec.into_iter().last().unwrap(), "y");
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod map {
+    extern crate alloc;
+    extern crate std;
+
+    use alloc::{string::String, vec::Vec};
+    use std::collections::HashMap;
+
+    use crate::{ShortStr, ShortStrMap};
+
+    use super::{strings, FACADE};
+
+    #[test]
+    fn matches_hash_map() {
+        let keys = strings();
+        let mut map = ShortStrMap::new();
+        let mut expected = HashMap::new();
+        for (value, key) in keys.iter().enumerate() {
+            assert_eq!(map.insert(ShortStr::from(key.as_str()), value), expected.insert(key, value));
+        }
+        assert_eq!(map.len(), expected.len());
+        for (index, key) in keys.iter().enumerate() {
+            assert_eq!(map.get(key), expected.get(key), "{key:?}");
+            if index % 3 == 0 {
+                assert_eq!(map.remove(key), expected.remove(key), "{key:?}");
+            }
+        }
+        assert_eq!(map.len(), expected.len());
+        for key in keys.iter().chain(&[String::from("missing"), "k".repeat(100)]) {
+            assert_eq!(map.get(key), expected.get(key), "{key:?}");
+            assert_eq!(map.contains_key(key), expected.contains_key(key));
+        }
+        assert_eq!(map.iter().len(), expected.len());
+        for (key, value) in &map {
+            assert_eq!(expected.get(&String::from(key.as_str())), Some(value));
+        }
+    }
+
+    #[test]
+    fn removing_keeps_colliding_keys_reachable() {
+        // every removal order of keys that all probe from the same few slots
+        let keys: Vec<String> = (0..6).map(|i| "x".repeat(i)).collect();
+        for first in 0..keys.len() {
+            for second in 0..keys.len() {
+                let mut map: ShortStrMap<usize> =
+                    keys.iter().enumerate().map(|(i, k)| (ShortStr::from(k.as_str()), i)).collect();
+                map.remove(&keys[first]);
+                map.remove(&keys[second]);
+                for (index, key) in keys.iter().enumerate() {
+                    let removed = index == first || index == second;
+                    assert_eq!(map.get(key), (!removed).then_some(&index));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sliced_and_unchecked_keys() {
+        let owned = String::from(FACADE);
+        let mut map = ShortStrMap::new();
+        map.insert(ShortStr::from("hello").slice(1..4), 1);
+        map.insert(unsafe { ShortStr::from_str_unchecked(&owned[..3]) }, 2);
+        map.insert(ShortStr::from(owned.as_str()), 3);
+        assert_eq!(map.get("ell"), Some(&1));
+        assert_eq!(map.get("a s"), Some(&2));
+        assert_eq!(map.get(FACADE), Some(&3));
+        assert_eq!(map.insert(ShortStr::from("ell"), 4), Some(1));
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn clear_and_reserve() {
+        let mut map = ShortStrMap::with_capacity(10);
+        let capacity = map.capacity();
+        assert!(capacity >= 10);
+        for i in 0..10 {
+            map.insert(ShortStr::from(["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"][i]), i);
+        }
+        assert_eq!(map.capacity(), capacity);
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.get("a"), None);
+        assert_eq!(map.capacity(), capacity);
+    }
+}
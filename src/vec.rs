@@ -0,0 +1,333 @@
+use alloc::vec::{self, Vec};
+use core::{
+    fmt::Debug,
+    iter::{Copied, FusedIterator},
+    slice,
+};
+
+use crate::{CoveringInt, ShortStr};
+
+// Number of values compared at once by contains, without an early exit within a block so that
+// the comparisons can be vectorized.
+const CONTAINS_BLOCK: usize = 8;
+
+/// A column of [`ShortStr`]s, stored contiguously as the words of their representation. Values
+/// are kept in the representation [`ShortStr::from`] produces, so inlined values can be
+/// compared as single integers in bulk operations like [`ShortStrVec::contains`].
+///
+/// # Examples
+/// These examples assume a little endian 64-bit architecture.
+/// ```
+/// use short_str::ShortStrVec;
+///
+/// let mut tokens: ShortStrVec = "let x = x + y".split(' ').collect();
+/// assert!(tokens.contains("let"));
+/// tokens.sort();
+/// tokens.dedup();
+/// assert_eq!(tokens.iter().collect::<Vec<_>>(), ["+", "=", "let", "x", "y"]);
+/// ```
+#[derive(Clone, Default)]
+pub struct ShortStrVec<'str_lt> {
+    // always canonical, see ShortStr::canonicalize
+    values: Vec<ShortStr<'str_lt>>,
+}
+
+impl<'str_lt> ShortStrVec<'str_lt> {
+    #[inline(always)]
+    /// Produce an empty [`ShortStrVec`], which doesn't allocate until a value is pushed.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrVec;
+    ///
+    /// assert_eq!(ShortStrVec::new().len(), 0);
+    /// ```
+    pub const fn new() -> Self {
+        ShortStrVec { values: Vec::new() }
+    }
+
+    #[inline(always)]
+    /// Produce an empty [`ShortStrVec`] with space for at least `capacity` values.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrVec;
+    ///
+    /// assert!(ShortStrVec::with_capacity(10).capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        ShortStrVec {
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the number of values the [`ShortStrVec`] can hold without reallocating.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrVec;
+    ///
+    /// assert_eq!(ShortStrVec::new().capacity(), 0);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.values.capacity()
+    }
+
+    #[inline(always)]
+    /// Returns the number of values in the [`ShortStrVec`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrVec;
+    ///
+    /// let tokens: ShortStrVec = ["a", "b"].into_iter().collect();
+    /// assert_eq!(tokens.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline(always)]
+    /// Returns `true` if the [`ShortStrVec`] holds no values.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrVec;
+    ///
+    /// assert_eq!(ShortStrVec::new().is_empty(), true);
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    #[inline(always)]
+    /// Append `value` to the [`ShortStrVec`].
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{ShortStr, ShortStrVec};
+    ///
+    /// let mut tokens = ShortStrVec::new();
+    /// tokens.push(ShortStr::from("token"));
+    /// assert_eq!(tokens.get(0), Some(ShortStr::from("token")));
+    /// ```
+    pub fn push(&mut self, value: ShortStr<'str_lt>) {
+        self.values.push(value.canonicalize());
+    }
+
+    #[inline(always)]
+    /// Remove the last value of the [`ShortStrVec`] and return it, or [`None`] if it's empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrVec;
+    ///
+    /// let mut tokens: ShortStrVec = ["a", "b"].into_iter().collect();
+    /// assert_eq!(tokens.pop().unwrap(), "b");
+    /// assert_eq!(tokens.len(), 1);
+    /// ```
+    pub fn pop(&mut self) -> Option<ShortStr<'str_lt>> {
+        self.values.pop()
+    }
+
+    #[inline(always)]
+    /// Returns the value at `index`, or [`None`] if it's out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrVec;
+    ///
+    /// let tokens: ShortStrVec = ["a", "b"].into_iter().collect();
+    /// assert_eq!(tokens.get(1).unwrap(), "b");
+    /// assert_eq!(tokens.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<ShortStr<'str_lt>> {
+        self.values.get(index).copied()
+    }
+
+    #[inline(always)]
+    /// View the values of the [`ShortStrVec`] as a slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::{ShortStr, ShortStrVec};
+    ///
+    /// let tokens: ShortStrVec = ["a", "b"].into_iter().collect();
+    /// assert_eq!(tokens.as_slice(), [ShortStr::from("a"), ShortStr::from("b")]);
+    /// ```
+    pub fn as_slice(&self) -> &[ShortStr<'str_lt>] {
+        &self.values
+    }
+
+    #[inline(always)]
+    /// Returns an iterator over the values of the [`ShortStrVec`], which keep the lifetime of the
+    /// original [`&str`]s.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrVec;
+    ///
+    /// let tokens: ShortStrVec = "a b".split(' ').collect();
+    /// assert_eq!(tokens.iter().collect::<Vec<_>>(), ["a", "b"]);
+    /// ```
+    pub fn iter(&self) -> ShortStrVecIter<'_, 'str_lt> {
+        ShortStrVecIter {
+            inner: self.values.iter().copied(),
+        }
+    }
+
+    /// Returns `true` if the [`ShortStrVec`] holds a value equal to `needle`.
+    ///
+    /// A needle that can be inlined is compared against each value as a single integer, blocks
+    /// of values at a time. Longer needles are only compared by bytes against values of the same
+    /// length.
+    ///
+    /// # Examples
+    /// These examples assume a little endian 64-bit architecture.
+    /// ```
+    /// use short_str::ShortStrVec;
+    ///
+    /// let tokens: ShortStrVec = ["main", "a_rather_long_identifier"].into_iter().collect();
+    /// assert!(tokens.contains("main"));
+    /// assert!(tokens.contains("a_rather_long_identifier"));
+    /// assert!(!tokens.contains("ma"));
+    /// ```
+    pub fn contains(&self, needle: &str) -> bool {
+        let needle = ShortStr::from_str(needle);
+        if needle.is_str() {
+            // canonical values with the content of a facade are facades themselves
+            return self.values.iter().any(|value| {
+                value.is_str() && value.len() == needle.len() && value.as_str() == needle.as_str()
+            });
+        }
+
+        // canonical values are normalized, so their raw bytes can be compared directly, and
+        // facades never share the marker of an inlined value
+        let needle = CoveringInt::from_ne_bytes(needle.to_bytes());
+        let matches = |value: &ShortStr| CoveringInt::from_ne_bytes(value.to_bytes()) == needle;
+        let mut blocks = self.values.chunks_exact(CONTAINS_BLOCK);
+        blocks.any(|block| block.iter().fold(false, |found, value| found | matches(value)))
+            || blocks.remainder().iter().any(matches)
+    }
+
+    /// Sort the values of the [`ShortStrVec`] by their content, like [`str`]s are ordered. The
+    /// sort is stable.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrVec;
+    ///
+    /// let mut tokens: ShortStrVec = ["b", "c", "a"].into_iter().collect();
+    /// tokens.sort();
+    /// assert_eq!(tokens.iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+    /// ```
+    pub fn sort(&mut self) {
+        self.values.sort();
+    }
+
+    /// Remove consecutive values with equal content, keeping the first of them.
+    ///
+    /// # Examples
+    /// ```
+    /// use short_str::ShortStrVec;
+    ///
+    /// let mut tokens: ShortStrVec = ["a", "a", "b", "a"].into_iter().collect();
+    /// tokens.dedup();
+    /// assert_eq!(tokens.iter().collect::<Vec<_>>(), ["a", "b", "a"]);
+    /// ```
+    pub fn dedup(&mut self) {
+        // canonical values with equal content are either both facades, or both inlined with the
+        // same bytes
+        self.values.dedup_by(|a, b| {
+            if a.is_str() && b.is_str() {
+                a.as_str() == b.as_str()
+            } else {
+                CoveringInt::from_ne_bytes(a.to_bytes()) == CoveringInt::from_ne_bytes(b.to_bytes())
+            }
+        });
+    }
+}
+
+impl Debug for ShortStrVec<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'str_lt> FromIterator<ShortStr<'str_lt>> for ShortStrVec<'str_lt> {
+    fn from_iter<T: IntoIterator<Item = ShortStr<'str_lt>>>(iter: T) -> Self {
+        let mut vec = ShortStrVec::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'str_lt> FromIterator<&'str_lt str> for ShortStrVec<'str_lt> {
+    fn from_iter<T: IntoIterator<Item = &'str_lt str>>(iter: T) -> Self {
+        iter.into_iter().map(ShortStr::from_str).collect()
+    }
+}
+
+impl<'str_lt> Extend<ShortStr<'str_lt>> for ShortStrVec<'str_lt> {
+    fn extend<T: IntoIterator<Item = ShortStr<'str_lt>>>(&mut self, iter: T) {
+        self.values.extend(iter.into_iter().map(ShortStr::canonicalize));
+    }
+}
+
+impl<'str_lt> Extend<&'str_lt str> for ShortStrVec<'str_lt> {
+    fn extend<T: IntoIterator<Item = &'str_lt str>>(&mut self, iter: T) {
+        self.values.extend(iter.into_iter().map(ShortStr::from_str));
+    }
+}
+
+impl<'str_lt> IntoIterator for ShortStrVec<'str_lt> {
+    type Item = ShortStr<'str_lt>;
+    type IntoIter = vec::IntoIter<ShortStr<'str_lt>>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'vec, 'str_lt> IntoIterator for &'vec ShortStrVec<'str_lt> {
+    type Item = ShortStr<'str_lt>;
+    type IntoIter = ShortStrVecIter<'vec, 'str_lt>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of a [`ShortStrVec`], see [`ShortStrVec::iter`].
+#[derive(Clone, Debug)]
+pub struct ShortStrVecIter<'vec, 'str_lt> {
+    inner: Copied<slice::Iter<'vec, ShortStr<'str_lt>>>,
+}
+
+impl<'str_lt> Iterator for ShortStrVecIter<'_, 'str_lt> {
+    type Item = ShortStr<'str_lt>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ShortStrVecIter<'_, '_> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl ExactSizeIterator for ShortStrVecIter<'_, '_> {}
+
+impl FusedIterator for ShortStrVecIter<'_, '_> {}